@@ -176,6 +176,16 @@ fn features_enabled() -> Vec<String> {
         names.push("sqlite".to_string());
     }
 
+    #[cfg(feature = "duckdb")]
+    {
+        names.push("duckdb".to_string());
+    }
+
+    #[cfg(feature = "odbc")]
+    {
+        names.push("odbc".to_string());
+    }
+
     #[cfg(feature = "dataframe")]
     {
         names.push("dataframe".to_string());
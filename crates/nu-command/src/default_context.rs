@@ -24,6 +24,10 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
         #[cfg(feature = "sqlite")]
         add_database_decls(&mut working_set);
 
+        // Adds all commands working against the shared in-memory DuckDB database
+        #[cfg(feature = "duckdb")]
+        add_stor_decls(&mut working_set);
+
         // Charts
         bind_command! {
             Histogram
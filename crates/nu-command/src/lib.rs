@@ -61,3 +61,9 @@ mod database;
 
 #[cfg(feature = "sqlite")]
 pub use database::*;
+
+#[cfg(feature = "duckdb")]
+mod stor;
+
+#[cfg(feature = "duckdb")]
+pub use stor::*;
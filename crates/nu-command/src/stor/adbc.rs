@@ -0,0 +1,179 @@
+use adbc_core::{
+    driver_manager::ManagedDriver,
+    options::{AdbcVersion, OptionDatabase},
+    Connection, Database, Driver, Statement,
+};
+// the explicit arrow crate pinned alongside adbc_core, not duckdb's
+// re-export: the batches come from the ADBC driver and the two deps track
+// different arrow majors
+use arrow::util::display::array_value_to_string;
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, Record, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct StorAdbcQuery;
+
+impl Command for StorAdbcQuery {
+    fn name(&self) -> &str {
+        "stor adbc query"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .required("sql", SyntaxShape::String, "SQL to run on the remote database")
+            .required_named(
+                "driver",
+                SyntaxShape::Filepath,
+                "path to the ADBC driver shared library",
+                Some('d'),
+            )
+            .named(
+                "uri",
+                SyntaxShape::String,
+                "connection URI passed to the driver",
+                Some('u'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Query an external database through an ADBC driver."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Arrow Database Connectivity drivers are loaded as shared libraries, so any
+database with an ADBC driver (PostgreSQL, Snowflake, ...) can be queried
+without teaching nushell its wire protocol. Cell values are currently
+returned in their display form."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Query PostgreSQL through its ADBC driver",
+            example: r#"stor adbc query "SELECT * FROM users" --driver libadbc_driver_postgresql.so --uri postgresql://localhost/app"#,
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "adbc", "arrow", "remote", "client"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let sql: String = call.req(engine_state, stack, 0)?;
+        let driver: Spanned<String> = call
+            .get_flag(engine_state, stack, "driver")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "driver".into(),
+                span,
+            })?;
+        let uri: Option<String> = call.get_flag(engine_state, stack, "uri")?;
+
+        let rows = run_adbc_query(&driver.item, uri.as_deref(), &sql, span)?;
+        Ok(Value::list(rows, span).into_pipeline_data())
+    }
+}
+
+fn run_adbc_query(
+    driver_path: &str,
+    uri: Option<&str>,
+    sql: &str,
+    span: Span,
+) -> Result<Vec<Value>, ShellError> {
+    let adbc_error = |e: adbc_core::error::Error| {
+        ShellError::GenericError(
+            "ADBC driver error".into(),
+            e.to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    };
+
+    let mut driver = ManagedDriver::load_dynamic_from_filename(driver_path, None, AdbcVersion::V110)
+        .map_err(adbc_error)?;
+
+    let opts = uri
+        .map(|uri| vec![(OptionDatabase::Uri, uri.into())])
+        .unwrap_or_default();
+    let mut database = driver.new_database_with_opts(opts).map_err(adbc_error)?;
+    let mut connection = database.new_connection().map_err(adbc_error)?;
+    let mut statement = connection.new_statement().map_err(adbc_error)?;
+
+    statement.set_sql_query(sql).map_err(adbc_error)?;
+    let reader = statement.execute().map_err(adbc_error)?;
+
+    let mut rows = Vec::new();
+    for batch in reader {
+        let batch = batch.map_err(|e| {
+            ShellError::GenericError(
+                "Failed to read ADBC result batch".into(),
+                e.to_string(),
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+        let column_names: Vec<String> = batch
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect();
+
+        for row in 0..batch.num_rows() {
+            let mut record = Record::new();
+            for (i, name) in column_names.iter().enumerate() {
+                let column = batch.column(i);
+                let value = if column.is_null(row) {
+                    Value::nothing(span)
+                } else {
+                    match array_value_to_string(column, row) {
+                        Ok(text) => Value::string(text, span),
+                        Err(e) => Value::error(
+                            ShellError::GenericError(
+                                "Failed to render ADBC value".into(),
+                                e.to_string(),
+                                Some(span),
+                                None,
+                                Vec::new(),
+                            ),
+                            span,
+                        ),
+                    }
+                };
+                record.push(name.clone(), value);
+            }
+            rows.push(Value::record(record, span));
+        }
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorAdbcQuery {});
+    }
+}
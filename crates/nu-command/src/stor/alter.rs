@@ -0,0 +1,158 @@
+use super::create::duckdb_type_for;
+use super::db::{quote_ident, run_stor_execute, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct StorAlter;
+
+impl Command for StorAlter {
+    fn name(&self) -> &str {
+        "stor alter"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("table", SyntaxShape::String, "table to alter")
+            .named(
+                "add-column",
+                SyntaxShape::Record(vec![]),
+                "columns to add, as {name: type}",
+                Some('a'),
+            )
+            .named(
+                "drop-column",
+                SyntaxShape::String,
+                "column to drop",
+                Some('d'),
+            )
+            .named(
+                "rename-column",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "rename a column, as [old new]",
+                Some('r'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Apply simple schema changes to a table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Generates the matching ALTER TABLE statements so everyday schema
+tweaks don't need hand-written SQL. Column types in --add-column accept the
+same names as `stor create --schema` (int, float, string, ...) as well as
+raw DuckDB types. The flags can be combined and apply in the order
+add, rename, drop."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Add two columns",
+                example: "stor alter logs --add-column {host: string, pid: int}",
+                result: None,
+            },
+            Example {
+                description: "Rename a column",
+                example: "stor alter logs --rename-column [msg message]",
+                result: None,
+            },
+            Example {
+                description: "Drop a column",
+                example: "stor alter logs --drop-column scratch",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "alter", "schema", "column", "rename"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let table: String = call.req(engine_state, stack, 0)?;
+        let add: Option<Value> = call.get_flag(engine_state, stack, "add-column")?;
+        let drop: Option<String> = call.get_flag(engine_state, stack, "drop-column")?;
+        let rename: Option<Vec<String>> = call.get_flag(engine_state, stack, "rename-column")?;
+
+        let mut statements = Vec::new();
+        if let Some(add) = &add {
+            for (column, type_name) in add.as_record()?.iter() {
+                statements.push(format!(
+                    "ALTER TABLE {} ADD COLUMN {} {}",
+                    quote_ident(&table),
+                    quote_ident(column),
+                    duckdb_type_for(&type_name.as_string()?)
+                ));
+            }
+        }
+        if let Some(rename) = &rename {
+            let [old, new] = rename.as_slice() else {
+                return Err(ShellError::GenericError(
+                    "Invalid --rename-column value".into(),
+                    format!("expected [old new], got {} names", rename.len()),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                ));
+            };
+            statements.push(format!(
+                "ALTER TABLE {} RENAME COLUMN {} TO {}",
+                quote_ident(&table),
+                quote_ident(old),
+                quote_ident(new)
+            ));
+        }
+        if let Some(drop) = &drop {
+            statements.push(format!(
+                "ALTER TABLE {} DROP COLUMN {}",
+                quote_ident(&table),
+                quote_ident(drop)
+            ));
+        }
+
+        if statements.is_empty() {
+            return Err(ShellError::GenericError(
+                "Nothing to alter".into(),
+                "pass --add-column, --drop-column, or --rename-column".into(),
+                Some(span),
+                None,
+                Vec::new(),
+            ));
+        }
+
+        let conn = stor_connection(span)?;
+        for statement in statements {
+            run_stor_execute(&conn, &statement, span)?;
+        }
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorAlter {});
+    }
+}
@@ -0,0 +1,191 @@
+use super::db::{ensure_table_for_record, stor_connection, NuValueParam};
+use duckdb::types::ToSql;
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    record, Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct StorAppend;
+
+impl Command for StorAppend {
+    fn name(&self) -> &str {
+        "stor append"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::Record(vec![]), Type::Record(vec![])),
+                (Type::Table(vec![]), Type::Record(vec![])),
+            ])
+            .allow_variants_without_examples(true)
+            .required(
+                "table",
+                SyntaxShape::String,
+                "table the piped records are appended to",
+            )
+            .named(
+                "batch-size",
+                SyntaxShape::Int,
+                "rows per flush to the database (default 10000)",
+                Some('b'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Bulk load piped records using DuckDB's Appender."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The fast path for large loads: rows go through the Appender API in
+batches instead of row-by-row INSERT statements. The table is created from
+the first record if it does not exist. Returns a summary with the row count
+and load rate."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Bulk load a large file",
+            example: "open --raw big.ndjson | lines | each { from json } | stor append events",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "append", "bulk", "load", "fast"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let table: String = call.req(engine_state, stack, 0)?;
+        let batch_size: i64 = call
+            .get_flag(engine_state, stack, "batch-size")?
+            .unwrap_or(10_000);
+        let ctrlc = engine_state.ctrlc.clone();
+
+        let conn = stor_connection(span)?;
+        let started = std::time::Instant::now();
+        let mut appender: Option<duckdb::Appender> = None;
+        let mut columns: Vec<String> = Vec::new();
+        let mut rows: i64 = 0;
+
+        for value in input {
+            if nu_utils::ctrl_c::was_pressed(&ctrlc) {
+                break;
+            }
+
+            let value_span = value.span();
+            let Value::Record { val: record, .. } = value else {
+                return Err(ShellError::OnlySupportsThisInputType {
+                    exp_input_type: "record".into(),
+                    wrong_type: value.get_type().to_string(),
+                    dst_span: span,
+                    src_span: value_span,
+                });
+            };
+
+            if appender.is_none() {
+                columns = ensure_table_for_record(&conn, &table, &record, span)?;
+                appender = Some(conn.appender(&table).map_err(|e| {
+                    ShellError::GenericError(
+                        format!("Failed to open appender on {table}"),
+                        e.to_string(),
+                        Some(span),
+                        None,
+                        Vec::new(),
+                    )
+                })?);
+            }
+            let appender = appender.as_mut().expect("appender was just created");
+
+            let nothing = Value::nothing(span);
+            let values: Vec<&Value> = columns
+                .iter()
+                .map(|col| {
+                    record
+                        .iter()
+                        .find(|(k, _)| *k == col)
+                        .map(|(_, v)| v)
+                        .unwrap_or(&nothing)
+                })
+                .collect();
+            let wrapped: Vec<NuValueParam> = values.into_iter().map(NuValueParam).collect();
+            let refs: Vec<&dyn ToSql> = wrapped.iter().map(|p| p as &dyn ToSql).collect();
+
+            appender.append_row(&refs[..]).map_err(|e| {
+                ShellError::GenericError(
+                    format!("Failed to append to {table}"),
+                    e.to_string(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                )
+            })?;
+            rows += 1;
+
+            if rows % batch_size.max(1) == 0 {
+                appender.flush().map_err(|e| {
+                    ShellError::GenericError(
+                        format!("Failed to flush appender on {table}"),
+                        e.to_string(),
+                        Some(span),
+                        None,
+                        Vec::new(),
+                    )
+                })?;
+            }
+        }
+
+        if let Some(mut appender) = appender {
+            appender.flush().map_err(|e| {
+                ShellError::GenericError(
+                    format!("Failed to flush appender on {table}"),
+                    e.to_string(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                )
+            })?;
+        }
+
+        let elapsed = started.elapsed();
+        let rate = if elapsed.as_secs_f64() > 0.0 {
+            rows as f64 / elapsed.as_secs_f64()
+        } else {
+            rows as f64
+        };
+
+        Ok(Value::record(
+            record! {
+                "rows" => Value::int(rows, span),
+                "duration" => Value::duration(elapsed.as_nanos() as i64, span),
+                "rows_per_sec" => Value::float(rate, span),
+            },
+            span,
+        )
+        .into_pipeline_data())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorAppend {});
+    }
+}
@@ -0,0 +1,189 @@
+use super::db::{quote_ident, run_stor_execute, run_stor_query, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+
+fn ensure_snapshots_table(conn: &duckdb::Connection, span: Span) -> Result<(), ShellError> {
+    run_stor_execute(
+        conn,
+        "CREATE TABLE IF NOT EXISTS nu_stor_snapshots (
+            table_name VARCHAR,
+            snapshot_table VARCHAR,
+            taken_at BIGINT
+        )",
+        span,
+    )?;
+    Ok(())
+}
+
+#[derive(Clone)]
+pub struct StorSnapshot;
+
+impl Command for StorSnapshot {
+    fn name(&self) -> &str {
+        "stor snapshot"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("table", SyntaxShape::String, "table to snapshot")
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Take a timestamped snapshot of a table for later time travel with `stor asof`."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Snapshot the sales table",
+            example: "stor snapshot sales",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "snapshot", "time travel", "backup"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let table: String = call.req(engine_state, stack, 0)?;
+        let taken_at = chrono::Utc::now().timestamp();
+
+        let conn = stor_connection(span)?;
+        ensure_snapshots_table(&conn, span)?;
+
+        let snapshot_table = format!("nu_snap_{table}_{taken_at}");
+        run_stor_execute(
+            &conn,
+            &format!(
+                "CREATE TABLE {} AS SELECT * FROM {}",
+                quote_ident(&snapshot_table),
+                quote_ident(&table)
+            ),
+            span,
+        )?;
+        run_stor_execute(
+            &conn,
+            &format!(
+                "INSERT INTO nu_stor_snapshots VALUES ('{}', '{}', {})",
+                table.replace('\'', "''"),
+                snapshot_table.replace('\'', "''"),
+                taken_at
+            ),
+            span,
+        )?;
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[derive(Clone)]
+pub struct StorAsof;
+
+impl Command for StorAsof {
+    fn name(&self) -> &str {
+        "stor asof"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .required("table", SyntaxShape::String, "table to read")
+            .required(
+                "when",
+                SyntaxShape::DateTime,
+                "return the table contents as of this moment",
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Read a table as it looked at an earlier point in time, from its snapshots."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Returns the newest snapshot taken at or before the given moment. Snapshots
+are created explicitly with `stor snapshot`."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Look at the sales table as of yesterday",
+            example: "stor asof sales ((date now) - 1day)",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "snapshot", "time travel", "history"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let table: String = call.req(engine_state, stack, 0)?;
+        let when: Value = call.req(engine_state, stack, 1)?;
+        let when = when.as_date()?.timestamp();
+
+        let conn = stor_connection(span)?;
+        ensure_snapshots_table(&conn, span)?;
+
+        let snapshot_table: String = conn
+            .query_row(
+                "SELECT snapshot_table FROM nu_stor_snapshots
+                 WHERE table_name = ? AND taken_at <= ?
+                 ORDER BY taken_at DESC LIMIT 1",
+                duckdb::params![table, when],
+                |row| row.get(0),
+            )
+            .map_err(|e| {
+                ShellError::GenericError(
+                    format!("No snapshot of {table} at or before the requested time"),
+                    e.to_string(),
+                    Some(span),
+                    Some("take snapshots with `stor snapshot`".into()),
+                    Vec::new(),
+                )
+            })?;
+
+        run_stor_query(
+            &conn,
+            &format!("SELECT * FROM {}", quote_ident(&snapshot_table)),
+            span,
+        )
+        .map(IntoPipelineData::into_pipeline_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorSnapshot {});
+        test_examples(StorAsof {});
+    }
+}
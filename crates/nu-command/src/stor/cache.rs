@@ -0,0 +1,250 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// Query results cached by their SQL text, together with the moment they were
+// stored. Entries older than the configured TTL are ignored and evicted on
+// the next lookup.
+static CACHE: Lazy<Mutex<HashMap<String, (Instant, Value)>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+// The cache TTL; `None` means caching is disabled entirely.
+static TTL: Lazy<Mutex<Option<Duration>>> = Lazy::new(|| Mutex::new(None));
+
+/// Look up a fresh cached result for the given SQL text.
+pub(super) fn lookup(sql: &str) -> Option<Value> {
+    let ttl = (*TTL.lock().ok()?)?;
+    let mut cache = CACHE.lock().ok()?;
+
+    match cache.get(sql) {
+        Some((stored, value)) if stored.elapsed() <= ttl => Some(value.clone()),
+        Some(_) => {
+            cache.remove(sql);
+            None
+        }
+        None => None,
+    }
+}
+
+/// Store a query result under its SQL text, if caching is enabled. Only
+/// SELECT statements are worth caching; everything else is ignored.
+pub(super) fn store(sql: &str, value: &Value) {
+    let enabled = matches!(TTL.lock().map(|ttl| ttl.is_some()), Ok(true));
+    if !enabled || !sql.trim_start().to_lowercase().starts_with("select") {
+        return;
+    }
+
+    if let Ok(mut cache) = CACHE.lock() {
+        cache.insert(sql.to_string(), (Instant::now(), value.clone()));
+    }
+}
+
+/// Drop every cached result. The db helpers call this after any statement
+/// that may have written, so cached reads never return rows from before a
+/// write within the TTL window.
+pub(super) fn invalidate() {
+    if let Ok(mut cache) = CACHE.lock() {
+        cache.clear();
+    }
+}
+
+#[derive(Clone)]
+pub struct StorCacheEnable;
+
+impl Command for StorCacheEnable {
+    fn name(&self) -> &str {
+        "stor cache enable"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required_named(
+                "ttl",
+                SyntaxShape::Duration,
+                "how long cached results stay valid",
+                Some('t'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Cache stor query results, keyed by their SQL text."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "While enabled, a repeated SELECT within the TTL is answered from the cache
+instead of hitting DuckDB. Only SELECT statements are cached, and any write
+that goes through the stor commands drops the cached results, so reads
+never see rows from before a write."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Cache query results for thirty seconds",
+            example: "stor cache enable --ttl 30sec",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "cache", "ttl"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let ttl: Value = call
+            .get_flag(engine_state, stack, "ttl")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "ttl".into(),
+                span,
+            })?;
+        let ttl_ns = ttl.as_duration()?;
+
+        if ttl_ns <= 0 {
+            return Err(ShellError::GenericError(
+                "Cache TTL must be positive".into(),
+                format!("got {ttl_ns}ns"),
+                Some(span),
+                None,
+                Vec::new(),
+            ));
+        }
+
+        if let Ok(mut ttl) = TTL.lock() {
+            *ttl = Some(Duration::from_nanos(ttl_ns as u64));
+        }
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[derive(Clone)]
+pub struct StorCacheDisable;
+
+impl Command for StorCacheDisable {
+    fn name(&self) -> &str {
+        "stor cache disable"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Stop caching stor query results and drop the cache."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Disable the result cache",
+            example: "stor cache disable",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "cache"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        _call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        if let Ok(mut ttl) = TTL.lock() {
+            *ttl = None;
+        }
+        if let Ok(mut cache) = CACHE.lock() {
+            cache.clear();
+        }
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[derive(Clone)]
+pub struct StorCacheClear;
+
+impl Command for StorCacheClear {
+    fn name(&self) -> &str {
+        "stor cache clear"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Int)])
+            .allow_variants_without_examples(true)
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Drop all cached query results, returning how many were dropped."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Clear the result cache",
+            example: "stor cache clear",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "cache"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let dropped = match CACHE.lock() {
+            Ok(mut cache) => {
+                let dropped = cache.len();
+                cache.clear();
+                dropped
+            }
+            Err(_) => 0,
+        };
+
+        Ok(Value::int(dropped as i64, span).into_pipeline_data())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorCacheEnable {});
+        test_examples(StorCacheDisable {});
+        test_examples(StorCacheClear {});
+    }
+}
@@ -0,0 +1,188 @@
+use super::db::{quote_ident, run_stor_execute, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    record, Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+use once_cell::sync::Lazy;
+use std::sync::Mutex;
+
+// The temp tables materialized by `stor query --cache`, with the query each
+// one captured. The tables themselves live in DuckDB's temp catalog; this
+// registry remembers where they came from.
+static CACHED: Lazy<Mutex<Vec<(String, String)>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// Remember that `name` now holds the materialized result of `sql`.
+pub(super) fn register_cached(name: &str, sql: &str) {
+    if let Ok(mut cached) = CACHED.lock() {
+        cached.retain(|(existing, _)| existing != name);
+        cached.push((name.to_string(), sql.to_string()));
+    }
+}
+
+// Cached-result names become table names, so only plain identifiers are
+// accepted.
+pub(super) fn validated_cache_name(name: &str, span: Span) -> Result<String, ShellError> {
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+    {
+        Ok(name.to_string())
+    } else {
+        Err(ShellError::GenericError(
+            format!("Invalid cache name {name}"),
+            "cache names must be plain identifiers".into(),
+            Some(span),
+            None,
+            Vec::new(),
+        ))
+    }
+}
+
+#[derive(Clone)]
+pub struct StorCachedList;
+
+impl Command for StorCachedList {
+    fn name(&self) -> &str {
+        "stor cached list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "List the results materialized by `stor query --cache`."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "See which results are materialized",
+            example: "stor cached list",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "cache", "materialize", "temp"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let conn = stor_connection(span)?;
+
+        let cached: Vec<(String, String)> = CACHED
+            .lock()
+            .map(|cached| cached.clone())
+            .unwrap_or_default();
+
+        let rows = cached
+            .into_iter()
+            .map(|(name, sql)| {
+                let count: Option<i64> = conn
+                    .query_row(
+                        &format!("SELECT count(*) FROM {}", quote_ident(&name)),
+                        [],
+                        |row| row.get(0),
+                    )
+                    .ok();
+                Value::record(
+                    record! {
+                        "name" => Value::string(name, span),
+                        "sql" => Value::string(sql, span),
+                        "rows" => match count {
+                            Some(count) => Value::int(count, span),
+                            None => Value::nothing(span),
+                        },
+                    },
+                    span,
+                )
+            })
+            .collect();
+
+        Ok(Value::list(rows, span).into_pipeline_data())
+    }
+}
+
+#[derive(Clone)]
+pub struct StorCachedDrop;
+
+impl Command for StorCachedDrop {
+    fn name(&self) -> &str {
+        "stor cached drop"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("name", SyntaxShape::String, "cached result to drop")
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Drop a result materialized by `stor query --cache`."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Free a cached result",
+            example: "stor cached drop expensive",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "cache", "drop", "free"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        let name = validated_cache_name(&name, span)?;
+
+        let conn = stor_connection(span)?;
+        run_stor_execute(
+            &conn,
+            &format!("DROP TABLE IF EXISTS {}", quote_ident(&name)),
+            span,
+        )?;
+        if let Ok(mut cached) = CACHED.lock() {
+            cached.retain(|(existing, _)| existing != &name);
+        }
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorCachedList {});
+        test_examples(StorCachedDrop {});
+    }
+}
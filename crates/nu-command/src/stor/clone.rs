@@ -0,0 +1,132 @@
+use super::db::{quote_ident, run_stor_execute, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct StorClone;
+
+impl Command for StorClone {
+    fn name(&self) -> &str {
+        "stor clone"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("source", SyntaxShape::String, "table to copy")
+            .required("destination", SyntaxShape::String, "name of the copy")
+            .named(
+                "to",
+                SyntaxShape::Filepath,
+                "create the copy inside this DuckDB file instead of in memory",
+                None,
+            )
+            .switch(
+                "schema-only",
+                "copy the column definitions but none of the rows",
+                Some('s'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Duplicate a table, in the store or into a DuckDB file."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "A plain clone makes a scratch copy before destructive experiments;
+--to attaches the given file and creates the copy there, which is an easy
+way to hand a single table to another DuckDB session."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Make a scratch copy before experimenting",
+                example: "stor clone users users_backup",
+                result: None,
+            },
+            Example {
+                description: "Copy a table into a database file",
+                example: "stor clone users users --to export.duckdb",
+                result: None,
+            },
+            Example {
+                description: "Copy only the structure",
+                example: "stor clone users users_empty --schema-only",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "clone", "copy", "duplicate", "backup"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let source: String = call.req(engine_state, stack, 0)?;
+        let destination: String = call.req(engine_state, stack, 1)?;
+        let to: Option<Spanned<String>> = call.get_flag(engine_state, stack, "to")?;
+        let schema_only = call.has_flag("schema-only");
+
+        // LIMIT 0 keeps the column definitions while copying no rows.
+        let select = if schema_only {
+            format!("SELECT * FROM {} LIMIT 0", quote_ident(&source))
+        } else {
+            format!("SELECT * FROM {}", quote_ident(&source))
+        };
+
+        let conn = stor_connection(span)?;
+        match to {
+            None => {
+                run_stor_execute(
+                    &conn,
+                    &format!("CREATE TABLE {} AS {select}", quote_ident(&destination)),
+                    span,
+                )?;
+            }
+            Some(to) => {
+                let path = nu_path::expand_path_with(&to.item, std::env::current_dir()?);
+                let path = path.to_string_lossy().replace('\'', "''");
+                run_stor_execute(&conn, &format!("ATTACH '{path}' AS __stor_clone"), span)?;
+                let result = run_stor_execute(
+                    &conn,
+                    &format!(
+                        "CREATE TABLE __stor_clone.{} AS {select}",
+                        quote_ident(&destination)
+                    ),
+                    span,
+                );
+                // Detach even when the copy failed so the file isn't left open.
+                let _ = conn.execute_batch("DETACH __stor_clone");
+                result?;
+            }
+        }
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorClone {});
+    }
+}
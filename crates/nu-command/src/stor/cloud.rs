@@ -0,0 +1,659 @@
+use super::cached::validated_cache_name;
+use super::db::{run_stor_execute, run_stor_execute_redacted, stor_connection};
+use duckdb::Connection;
+use nu_engine::CallExt;
+use once_cell::sync::Lazy;
+use std::sync::Mutex;
+
+// The profiles registered through `stor cloud-init --name`, so several
+// scoped credentials can coexist and `stor cloud list` can show which
+// secret serves which bucket.
+static PROFILES: Lazy<Mutex<Vec<CloudProfile>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+#[derive(Clone)]
+struct CloudProfile {
+    name: String,
+    provider: String,
+    secret: String,
+    scope: Option<String>,
+}
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    record, Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct StorCloudInit;
+
+impl Command for StorCloudInit {
+    fn name(&self) -> &str {
+        "stor cloud-init"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::Nothing, Type::Nothing),
+                (Type::Nothing, Type::Record(vec![])),
+            ])
+            .allow_variants_without_examples(true)
+            .required(
+                "provider",
+                SyntaxShape::String,
+                "cloud provider to configure: aws, azure, gcs, or http",
+            )
+            .named(
+                "key",
+                SyntaxShape::String,
+                "access key id (aws) or HMAC key (gcs)",
+                Some('k'),
+            )
+            .named(
+                "secret",
+                SyntaxShape::String,
+                "secret access key (aws) or HMAC secret (gcs)",
+                Some('s'),
+            )
+            .named(
+                "profile",
+                SyntaxShape::String,
+                "AWS profile from the shared config to use (aws)",
+                Some('p'),
+            )
+            .named(
+                "region",
+                SyntaxShape::String,
+                "region the buckets live in (aws)",
+                Some('r'),
+            )
+            .named(
+                "session-token",
+                SyntaxShape::String,
+                "temporary session token to use alongside --key/--secret (aws)",
+                None,
+            )
+            .named(
+                "role-arn",
+                SyntaxShape::String,
+                "role to assume through STS before querying (aws)",
+                None,
+            )
+            .named(
+                "external-id",
+                SyntaxShape::String,
+                "external id for the assume-role call (aws)",
+                None,
+            )
+            .named(
+                "endpoint",
+                SyntaxShape::String,
+                "S3-compatible endpoint, e.g. a MinIO or R2 host (aws)",
+                Some('e'),
+            )
+            .named(
+                "url-style",
+                SyntaxShape::String,
+                "bucket addressing: path or vhost (aws)",
+                None,
+            )
+            .switch(
+                "no-ssl",
+                "talk plain http to the endpoint, for local MinIO setups (aws)",
+                None,
+            )
+            .switch(
+                "chain",
+                "resolve credentials through the standard AWS chain: env vars, shared config, IMDS (aws)",
+                None,
+            )
+            .named(
+                "connection",
+                SyntaxShape::String,
+                "storage connection string (azure)",
+                Some('c'),
+            )
+            .named(
+                "tenant",
+                SyntaxShape::String,
+                "tenant id for service-principal auth (azure)",
+                None,
+            )
+            .named(
+                "client-id",
+                SyntaxShape::String,
+                "client id for service-principal auth (azure)",
+                None,
+            )
+            .named(
+                "client-secret",
+                SyntaxShape::String,
+                "client secret for service-principal auth (azure)",
+                None,
+            )
+            .named(
+                "sas",
+                SyntaxShape::String,
+                "shared access signature token (azure)",
+                None,
+            )
+            .named(
+                "account",
+                SyntaxShape::String,
+                "storage account the SAS token belongs to (azure)",
+                None,
+            )
+            .switch(
+                "managed-identity",
+                "authenticate with the machine's managed identity (azure)",
+                None,
+            )
+            .named(
+                "name",
+                SyntaxShape::String,
+                "register the credentials as a named profile instead of the provider default",
+                Some('n'),
+            )
+            .named(
+                "scope",
+                SyntaxShape::String,
+                "path prefix the credentials apply to, e.g. s3://prod-data/",
+                None,
+            )
+            .switch(
+                "from-env",
+                "fill missing credentials from the shell environment",
+                None,
+            )
+            .named(
+                "verify",
+                SyntaxShape::String,
+                "after configuring, list this remote path to prove the credentials work",
+                Some('v'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Configure cloud storage credentials so remote URLs are queryable."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Installs and loads the extension the provider needs (httpfs for aws
+and gcs, azure for azure) and registers the credentials with the session
+connection. Afterwards s3://, gs://, and az:// paths work directly in
+`stor query`. Temporary credentials (--session-token, --role-arn) are held
+in a replaceable secret: run the command again to refresh them once they
+expire. With --verify a trivial listing runs against the given path right
+away, so misconfigured credentials fail here with the provider's error
+rather than deep inside a later query. --from-env reads whatever the flags
+don't provide from the usual environment variables (AWS_ACCESS_KEY_ID /
+AWS_SECRET_ACCESS_KEY / AWS_SESSION_TOKEN / AWS_REGION,
+AZURE_STORAGE_CONNECTION_STRING, GCS_ACCESS_KEY_ID / GCS_SECRET_ACCESS_KEY)
+so credentials never appear on the command line or in its history; the
+CREATE SECRET statements are logged to `stor history` with their values
+redacted. Each --name keeps its
+own secret, so profiles for several accounts or buckets can be registered
+side by side (scoped with --scope) instead of overwriting one global
+credential; `stor cloud list` shows them."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Separate credentials per bucket",
+                example: r#"stor cloud-init aws --name prod --profile prod --scope "s3://prod-data/""#,
+                result: None,
+            },
+            Example {
+                description: "Read plain https:// files, no credentials needed",
+                example: "stor cloud-init http",
+                result: None,
+            },
+            Example {
+                description: "Query S3 with explicit keys",
+                example: "stor cloud-init aws --key $env.KEY --secret $env.SECRET",
+                result: None,
+            },
+            Example {
+                description: "Query S3 with whatever the AWS credential chain finds",
+                example: "stor cloud-init aws --chain --profile prod",
+                result: None,
+            },
+            Example {
+                description: "Configure S3 from the environment, typing no credentials",
+                example: "stor cloud-init aws --from-env",
+                result: None,
+            },
+            Example {
+                description: "Configure and immediately prove the credentials work",
+                example: r#"stor cloud-init aws --chain --verify "s3://prod-data/*.parquet""#,
+                result: None,
+            },
+            Example {
+                description: "Query a local MinIO bucket",
+                example: "stor cloud-init aws --key minio --secret minio123 --endpoint localhost:9000 --url-style path --no-ssl",
+                result: None,
+            },
+            Example {
+                description: "Query S3 after assuming a role in a locked-down account",
+                example: "stor cloud-init aws --role-arn arn:aws:iam::123:role/reader --external-id audit",
+                result: None,
+            },
+            Example {
+                description: "Query Google Cloud Storage with HMAC credentials",
+                example: "stor cloud-init gcs --key $env.HMAC_KEY --secret $env.HMAC_SECRET",
+                result: None,
+            },
+            Example {
+                description: "Query Azure blob storage",
+                example: "stor cloud-init azure --connection $env.AZURE_STORAGE_CONNECTION_STRING",
+                result: None,
+            },
+            Example {
+                description: "Query a container through a SAS token",
+                example: "stor cloud-init azure --account contoso --sas $env.SAS_TOKEN",
+                result: None,
+            },
+            Example {
+                description: "Query az:// paths as a service principal, without account keys",
+                example: "stor cloud-init azure --tenant $env.TENANT --client-id $env.ID --client-secret $env.SECRET",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "cloud", "s3", "gcs", "azure", "credentials"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let provider: String = call.req(engine_state, stack, 0)?;
+        let key: Option<String> = call.get_flag(engine_state, stack, "key")?;
+        let secret: Option<String> = call.get_flag(engine_state, stack, "secret")?;
+        let connection: Option<String> = call.get_flag(engine_state, stack, "connection")?;
+        let profile: Option<String> = call.get_flag(engine_state, stack, "profile")?;
+        let region: Option<String> = call.get_flag(engine_state, stack, "region")?;
+        let chain = call.has_flag("chain");
+        let session_token: Option<String> = call.get_flag(engine_state, stack, "session-token")?;
+        let role_arn: Option<String> = call.get_flag(engine_state, stack, "role-arn")?;
+        let external_id: Option<String> = call.get_flag(engine_state, stack, "external-id")?;
+        let tenant: Option<String> = call.get_flag(engine_state, stack, "tenant")?;
+        let client_id: Option<String> = call.get_flag(engine_state, stack, "client-id")?;
+        let client_secret: Option<String> = call.get_flag(engine_state, stack, "client-secret")?;
+        let managed_identity = call.has_flag("managed-identity");
+        let sas: Option<String> = call.get_flag(engine_state, stack, "sas")?;
+        let endpoint: Option<String> = call.get_flag(engine_state, stack, "endpoint")?;
+        let url_style: Option<String> = call.get_flag(engine_state, stack, "url-style")?;
+        let no_ssl = call.has_flag("no-ssl");
+        let from_env = call.has_flag("from-env");
+        let profile_name: Option<String> = call.get_flag(engine_state, stack, "name")?;
+        let scope: Option<String> = call.get_flag(engine_state, stack, "scope")?;
+
+        let secret_name = match &profile_name {
+            Some(name) => format!("stor_{provider}_{}", validated_cache_name(name, span)?),
+            None => format!("stor_{provider}"),
+        };
+        let scope_field = scope
+            .as_ref()
+            .map(|scope| format!(", SCOPE '{}'", sql_escape(scope)))
+            .unwrap_or_default();
+
+        let mut key = key;
+        let mut secret = secret;
+        let mut session_token = session_token;
+        let mut region = region;
+        let mut connection = connection;
+        if from_env {
+            let env = |name: &str| env_string(engine_state, stack, name);
+            match provider.as_str() {
+                "aws" => {
+                    key = key.or_else(|| env("AWS_ACCESS_KEY_ID"));
+                    secret = secret.or_else(|| env("AWS_SECRET_ACCESS_KEY"));
+                    session_token = session_token.or_else(|| env("AWS_SESSION_TOKEN"));
+                    region = region
+                        .or_else(|| env("AWS_REGION"))
+                        .or_else(|| env("AWS_DEFAULT_REGION"));
+                }
+                "azure" => {
+                    connection = connection.or_else(|| env("AZURE_STORAGE_CONNECTION_STRING"));
+                }
+                "gcs" => {
+                    key = key.or_else(|| env("GCS_ACCESS_KEY_ID"));
+                    secret = secret.or_else(|| env("GCS_SECRET_ACCESS_KEY"));
+                }
+                _ => {}
+            }
+        }
+        let account: Option<String> = call.get_flag(engine_state, stack, "account")?;
+
+        let conn = stor_connection(span)?;
+        match provider.as_str() {
+            "aws" => {
+                load_extension(&conn, "httpfs", span)?;
+                let mut fields = Vec::new();
+                if chain || profile.is_some() || role_arn.is_some() {
+                    // The credential-chain provider lives in the aws extension
+                    // and resolves env vars, shared config, IMDS, and STS
+                    // assume-role itself.
+                    load_extension(&conn, "aws", span)?;
+                    fields.push("PROVIDER CREDENTIAL_CHAIN".to_string());
+                    if role_arn.is_some() {
+                        fields.push("CHAIN 'sts'".to_string());
+                    }
+                    if let Some(role_arn) = &role_arn {
+                        fields.push(format!("ASSUME_ROLE_ARN '{}'", sql_escape(role_arn)));
+                    }
+                    if let Some(external_id) = &external_id {
+                        fields.push(format!("EXTERNAL_ID '{}'", sql_escape(external_id)));
+                    }
+                    if let Some(profile) = &profile {
+                        fields.push(format!("PROFILE '{}'", sql_escape(profile)));
+                    }
+                } else {
+                    let (key, secret) = required_pair(key, secret, "aws", span)?;
+                    fields.push(format!("KEY_ID '{}'", sql_escape(&key)));
+                    fields.push(format!("SECRET '{}'", sql_escape(&secret)));
+                    if let Some(session_token) = &session_token {
+                        fields.push(format!("SESSION_TOKEN '{}'", sql_escape(session_token)));
+                    }
+                }
+                if let Some(region) = &region {
+                    fields.push(format!("REGION '{}'", sql_escape(region)));
+                }
+                if let Some(endpoint) = &endpoint {
+                    fields.push(format!("ENDPOINT '{}'", sql_escape(endpoint)));
+                }
+                if let Some(url_style) = &url_style {
+                    if url_style != "path" && url_style != "vhost" {
+                        return Err(ShellError::GenericError(
+                            format!("Invalid url style {url_style}"),
+                            "expected path or vhost".into(),
+                            Some(span),
+                            None,
+                            Vec::new(),
+                        ));
+                    }
+                    fields.push(format!("URL_STYLE '{url_style}'"));
+                }
+                if no_ssl {
+                    fields.push("USE_SSL false".to_string());
+                }
+                // history and hooks see the statement shape, not the values
+                run_stor_execute_redacted(
+                    &conn,
+                    &format!(
+                        "CREATE OR REPLACE SECRET {secret_name} (TYPE S3, {}{scope_field})",
+                        fields.join(", ")
+                    ),
+                    &format!("CREATE OR REPLACE SECRET {secret_name} (TYPE S3, [redacted])"),
+                    span,
+                )?;
+            }
+            "gcs" => {
+                load_extension(&conn, "httpfs", span)?;
+                let (key, secret) = required_pair(key, secret, "gcs", span)?;
+                run_stor_execute_redacted(
+                    &conn,
+                    &format!(
+                        "CREATE OR REPLACE SECRET {secret_name} (TYPE GCS, \
+                         KEY_ID '{}', SECRET '{}'{scope_field})",
+                        sql_escape(&key),
+                        sql_escape(&secret)
+                    ),
+                    &format!("CREATE OR REPLACE SECRET {secret_name} (TYPE GCS, [redacted])"),
+                    span,
+                )?;
+            }
+            "azure" => {
+                load_extension(&conn, "azure", span)?;
+                let fields = if managed_identity {
+                    "PROVIDER CREDENTIAL_CHAIN, CHAIN 'managed_identity'".to_string()
+                } else if tenant.is_some() || client_id.is_some() || client_secret.is_some() {
+                    let (Some(tenant), Some(client_id), Some(client_secret)) =
+                        (&tenant, &client_id, &client_secret)
+                    else {
+                        return Err(ShellError::GenericError(
+                            "Incomplete service principal".into(),
+                            "service-principal auth needs --tenant, --client-id, and --client-secret".into(),
+                            Some(span),
+                            None,
+                            Vec::new(),
+                        ));
+                    };
+                    format!(
+                        "PROVIDER SERVICE_PRINCIPAL, TENANT_ID '{}', \
+                         CLIENT_ID '{}', CLIENT_SECRET '{}'",
+                        sql_escape(tenant),
+                        sql_escape(client_id),
+                        sql_escape(client_secret)
+                    )
+                } else if let Some(sas) = &sas {
+                    let Some(account) = &account else {
+                        return Err(ShellError::GenericError(
+                            "Missing storage account".into(),
+                            "--sas also needs --account to name the storage account".into(),
+                            Some(span),
+                            None,
+                            Vec::new(),
+                        ));
+                    };
+                    // SAS tokens ride in over the connection string; a URL
+                    // pasted with a leading ? works as-is.
+                    let connection = format!(
+                        "AccountName={};SharedAccessSignature={}",
+                        account,
+                        sas.trim_start_matches('?')
+                    );
+                    format!("CONNECTION_STRING '{}'", sql_escape(&connection))
+                } else if let Some(connection) = &connection {
+                    format!("CONNECTION_STRING '{}'", sql_escape(connection))
+                } else {
+                    return Err(ShellError::GenericError(
+                        "Missing azure credentials".into(),
+                        "pass --connection, --sas, --tenant/--client-id/--client-secret, or --managed-identity".into(),
+                        Some(span),
+                        None,
+                        Vec::new(),
+                    ));
+                };
+                run_stor_execute_redacted(
+                    &conn,
+                    &format!(
+                        "CREATE OR REPLACE SECRET {secret_name} (TYPE AZURE, {fields}{scope_field})"
+                    ),
+                    &format!("CREATE OR REPLACE SECRET {secret_name} (TYPE AZURE, [redacted])"),
+                    span,
+                )?;
+            }
+            // Anonymous remote reads only need httpfs loaded.
+            "http" | "https" => load_extension(&conn, "httpfs", span)?,
+            other => {
+                return Err(ShellError::GenericError(
+                    format!("Unknown provider {other}"),
+                    "expected aws, azure, gcs, or http".into(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                ))
+            }
+        }
+
+        if provider != "http" && provider != "https" {
+            if let Ok(mut profiles) = PROFILES.lock() {
+                let name = profile_name.unwrap_or_else(|| "default".into());
+                profiles.retain(|p| !(p.name == name && p.provider == provider));
+                profiles.push(CloudProfile {
+                    name,
+                    provider: provider.clone(),
+                    secret: secret_name,
+                    scope,
+                });
+            }
+        }
+
+        if let Some(verify) = call.get_flag::<String>(engine_state, stack, "verify")? {
+            let files: i64 = conn
+                .query_row(
+                    &format!("SELECT count(*) FROM glob('{}')", sql_escape(&verify)),
+                    [],
+                    |row| row.get(0),
+                )
+                .map_err(|e| {
+                    ShellError::GenericError(
+                        format!("Verification against {verify} failed"),
+                        e.to_string(),
+                        Some(span),
+                        Some("the credentials were stored but the provider rejected them".into()),
+                        Vec::new(),
+                    )
+                })?;
+            return Ok(Value::record(
+                record! {
+                    "verified" => Value::bool(true, span),
+                    "path" => Value::string(verify, span),
+                    "files" => Value::int(files, span),
+                },
+                span,
+            )
+            .into_pipeline_data());
+        }
+
+        Ok(PipelineData::empty())
+    }
+}
+
+// Install and load an extension, tolerating it being installed already.
+pub(super) fn load_extension(
+    conn: &Connection,
+    name: &str,
+    span: Span,
+) -> Result<(), ShellError> {
+    run_stor_execute(conn, &format!("INSTALL {name}"), span)?;
+    run_stor_execute(conn, &format!("LOAD {name}"), span)?;
+    Ok(())
+}
+
+fn required_pair(
+    key: Option<String>,
+    secret: Option<String>,
+    provider: &str,
+    span: Span,
+) -> Result<(String, String), ShellError> {
+    match (key, secret) {
+        (Some(key), Some(secret)) => Ok((key, secret)),
+        _ => Err(ShellError::GenericError(
+            format!("Missing {provider} credentials"),
+            format!("the {provider} provider needs both --key and --secret"),
+            Some(span),
+            None,
+            Vec::new(),
+        )),
+    }
+}
+
+// Credential values land inside single-quoted SQL literals.
+pub(super) fn sql_escape(value: &str) -> String {
+    value.replace('\'', "''")
+}
+
+// A non-empty environment variable from the shell, if set.
+fn env_string(engine_state: &EngineState, stack: &Stack, name: &str) -> Option<String> {
+    stack
+        .get_env_var(engine_state, name)
+        .and_then(|value| value.as_string().ok())
+        .filter(|value| !value.is_empty())
+}
+
+#[derive(Clone)]
+pub struct StorCloudList;
+
+impl Command for StorCloudList {
+    fn name(&self) -> &str {
+        "stor cloud list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "List the cloud profiles registered this session."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "One row per `stor cloud-init` profile with the secret it created and
+the scope it covers; `stor secret list` shows the underlying secrets."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "See which accounts and buckets are configured",
+            example: "stor cloud list",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "cloud", "profile", "list"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let rows = PROFILES
+            .lock()
+            .map(|profiles| {
+                profiles
+                    .iter()
+                    .map(|profile| {
+                        Value::record(
+                            record! {
+                                "name" => Value::string(profile.name.clone(), span),
+                                "provider" => Value::string(profile.provider.clone(), span),
+                                "secret" => Value::string(profile.secret.clone(), span),
+                                "scope" => match &profile.scope {
+                                    Some(scope) => Value::string(scope.clone(), span),
+                                    None => Value::nothing(span),
+                                },
+                            },
+                            span,
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(Value::list(rows, span).into_pipeline_data())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorCloudInit {});
+        test_examples(StorCloudList {});
+    }
+}
@@ -0,0 +1,81 @@
+use super::db::{ensure_comments_table, run_stor_query, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct StorCommentList;
+
+impl Command for StorCommentList {
+    fn name(&self) -> &str {
+        "stor comment list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .named(
+                "table-name",
+                SyntaxShape::String,
+                "only show comments for this table",
+                Some('t'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "List the table and column descriptions stored in the in-memory database."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "List all stored comments",
+            example: "stor comment list",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "comment", "metadata"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let table_name: Option<String> = call.get_flag(engine_state, stack, "table-name")?;
+
+        let conn = stor_connection(span)?;
+        ensure_comments_table(&conn, span)?;
+
+        let sql = match table_name {
+            Some(table) => format!(
+                "SELECT table_name, column_name, comment FROM nu_stor_comments WHERE table_name = '{}'",
+                table.replace('\'', "''")
+            ),
+            None => "SELECT table_name, column_name, comment FROM nu_stor_comments".into(),
+        };
+
+        run_stor_query(&conn, &sql, span).map(IntoPipelineData::into_pipeline_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorCommentList {});
+    }
+}
@@ -0,0 +1,120 @@
+use super::db::{ensure_comments_table, run_stor_execute, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct StorCommentSet;
+
+impl Command for StorCommentSet {
+    fn name(&self) -> &str {
+        "stor comment set"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("comment", SyntaxShape::String, "description to store")
+            .required_named(
+                "table-name",
+                SyntaxShape::String,
+                "table the comment belongs to",
+                Some('t'),
+            )
+            .named(
+                "column",
+                SyntaxShape::String,
+                "comment on this column instead of the table itself",
+                Some('c'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Attach a description to a table or column in the in-memory database."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "DuckDB has no COMMENT ON statement, so descriptions are kept in the
+nu_stor_comments metadata table and surfaced by the introspection commands."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Describe the sales table",
+                example: r#"stor comment set "raw order lines from the ERP export" --table-name sales"#,
+                result: None,
+            },
+            Example {
+                description: "Describe a single column",
+                example: r#"stor comment set "net amount in EUR" --table-name sales --column amount"#,
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "comment", "metadata", "description"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let comment: String = call.req(engine_state, stack, 0)?;
+        let table_name: String = call
+            .get_flag(engine_state, stack, "table-name")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "table-name".into(),
+                span,
+            })?;
+        let column: Option<String> = call.get_flag(engine_state, stack, "column")?;
+
+        let conn = stor_connection(span)?;
+        ensure_comments_table(&conn, span)?;
+
+        let column = column.unwrap_or_default();
+        run_stor_execute(
+            &conn,
+            &format!(
+                "DELETE FROM nu_stor_comments WHERE table_name = '{}' AND column_name = '{}'",
+                table_name.replace('\'', "''"),
+                column.replace('\'', "''")
+            ),
+            span,
+        )?;
+        run_stor_execute(
+            &conn,
+            &format!(
+                "INSERT INTO nu_stor_comments VALUES ('{}', '{}', '{}')",
+                table_name.replace('\'', "''"),
+                column.replace('\'', "''"),
+                comment.replace('\'', "''")
+            ),
+            span,
+        )?;
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorCommentSet {});
+    }
+}
@@ -0,0 +1,157 @@
+use super::db::{quote_ident, run_stor_execute, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct StorConstraintAdd;
+
+impl Command for StorConstraintAdd {
+    fn name(&self) -> &str {
+        "stor constraint add"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required_named(
+                "table-name",
+                SyntaxShape::String,
+                "name of the table to constrain",
+                Some('t'),
+            )
+            .named(
+                "not-null",
+                SyntaxShape::String,
+                "column that should reject NULL values",
+                Some('n'),
+            )
+            .named(
+                "primary-key",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "columns forming a primary key",
+                Some('p'),
+            )
+            .named(
+                "unique",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "columns that must hold unique values",
+                Some('u'),
+            )
+            .named(
+                "check",
+                SyntaxShape::String,
+                "SQL expression every row must satisfy",
+                Some('c'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Add a constraint to a table in the in-memory database."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "DuckDB can only add NOT NULL constraints to existing tables. Primary key, unique,
+and check constraints have to be part of the CREATE TABLE definition; requesting one
+here produces an error explaining that instead of a confusing SQL failure."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Forbid NULLs in the name column of table nudb",
+            example: "stor constraint add --table-name nudb --not-null name",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "constraint", "primary key", "unique"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let table_name: String = call
+            .get_flag(engine_state, stack, "table-name")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "table-name".into(),
+                span,
+            })?;
+        let not_null: Option<String> = call.get_flag(engine_state, stack, "not-null")?;
+        let primary_key: Option<Vec<String>> = call.get_flag(engine_state, stack, "primary-key")?;
+        let unique: Option<Vec<String>> = call.get_flag(engine_state, stack, "unique")?;
+        let check: Option<String> = call.get_flag(engine_state, stack, "check")?;
+
+        if let Some(kind) = unsupported_constraint_kind(&primary_key, &unique, &check) {
+            return Err(unsupported_for_existing_table(kind, span));
+        }
+
+        let Some(column) = not_null else {
+            return Err(ShellError::MissingParameter {
+                param_name: "not-null, primary-key, unique or check".into(),
+                span,
+            });
+        };
+
+        let sql = format!(
+            "ALTER TABLE {} ALTER COLUMN {} SET NOT NULL",
+            quote_ident(&table_name),
+            quote_ident(&column)
+        );
+
+        let conn = stor_connection(span)?;
+        run_stor_execute(&conn, &sql, span)?;
+
+        Ok(PipelineData::empty())
+    }
+}
+
+fn unsupported_constraint_kind(
+    primary_key: &Option<Vec<String>>,
+    unique: &Option<Vec<String>>,
+    check: &Option<String>,
+) -> Option<&'static str> {
+    if primary_key.is_some() {
+        Some("primary key")
+    } else if unique.is_some() {
+        Some("unique")
+    } else if check.is_some() {
+        Some("check")
+    } else {
+        None
+    }
+}
+
+fn unsupported_for_existing_table(kind: &str, span: nu_protocol::Span) -> ShellError {
+    ShellError::GenericError(
+        format!("DuckDB cannot add {kind} constraints to an existing table"),
+        "only NOT NULL constraints can be added after table creation".into(),
+        Some(span),
+        Some(format!(
+            "recreate the table with the {kind} constraint in its CREATE TABLE definition"
+        )),
+        Vec::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorConstraintAdd {});
+    }
+}
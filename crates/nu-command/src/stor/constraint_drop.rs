@@ -0,0 +1,118 @@
+use super::db::{quote_ident, run_stor_execute, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct StorConstraintDrop;
+
+impl Command for StorConstraintDrop {
+    fn name(&self) -> &str {
+        "stor constraint drop"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required_named(
+                "table-name",
+                SyntaxShape::String,
+                "name of the constrained table",
+                Some('t'),
+            )
+            .named(
+                "not-null",
+                SyntaxShape::String,
+                "column that should accept NULL values again",
+                Some('n'),
+            )
+            .switch(
+                "primary-key",
+                "drop the table's primary key (unsupported by DuckDB)",
+                Some('p'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Drop a constraint from a table in the in-memory database."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Only NOT NULL constraints can be dropped from an existing DuckDB table; other
+constraint types have to be removed by recreating the table."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Allow NULLs in the name column of table nudb again",
+            example: "stor constraint drop --table-name nudb --not-null name",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "constraint"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let table_name: String = call
+            .get_flag(engine_state, stack, "table-name")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "table-name".into(),
+                span,
+            })?;
+        let not_null: Option<String> = call.get_flag(engine_state, stack, "not-null")?;
+
+        if call.has_flag("primary-key") {
+            return Err(ShellError::GenericError(
+                "DuckDB cannot drop a primary key from an existing table".into(),
+                "only NOT NULL constraints can be dropped after table creation".into(),
+                Some(span),
+                Some("recreate the table without the primary key constraint".into()),
+                Vec::new(),
+            ));
+        }
+
+        let Some(column) = not_null else {
+            return Err(ShellError::MissingParameter {
+                param_name: "not-null".into(),
+                span,
+            });
+        };
+
+        let sql = format!(
+            "ALTER TABLE {} ALTER COLUMN {} DROP NOT NULL",
+            quote_ident(&table_name),
+            quote_ident(&column)
+        );
+
+        let conn = stor_connection(span)?;
+        run_stor_execute(&conn, &sql, span)?;
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorConstraintDrop {});
+    }
+}
@@ -0,0 +1,170 @@
+use super::db::{run_stor_query_params, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+// The old SQLite-backed stor used pragma table_info/foreign_key_list for
+// this; DuckDB puts everything in the duckdb_constraints() catalog function.
+
+#[derive(Clone)]
+pub struct StorConstraints;
+
+impl Command for StorConstraints {
+    fn name(&self) -> &str {
+        "stor constraints"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .optional(
+                "table",
+                SyntaxShape::String,
+                "only show constraints on this table",
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "List the constraints defined in the in-memory database."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Covers primary keys, unique, check, not-null, and foreign-key
+constraints; `stor foreign-keys` shows just the last of those with the
+referenced side broken out."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "List every constraint",
+                example: "stor constraints",
+                result: None,
+            },
+            Example {
+                description: "Constraints on one table",
+                example: "stor constraints users",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "constraint", "primary", "unique", "check"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let table: Option<String> = call.opt(engine_state, stack, 0)?;
+        let conn = stor_connection(span)?;
+
+        let mut sql = "SELECT table_name, constraint_type, constraint_text, \
+                       constraint_column_names AS columns \
+                       FROM duckdb_constraints()"
+            .to_string();
+        let mut params = Vec::new();
+        if let Some(table) = table {
+            sql.push_str(" WHERE table_name = ?");
+            params.push(Value::string(table, span));
+        }
+        sql.push_str(" ORDER BY table_name, constraint_index");
+
+        run_stor_query_params(&conn, &sql, &params, span)
+            .map(IntoPipelineData::into_pipeline_data)
+    }
+}
+
+#[derive(Clone)]
+pub struct StorForeignKeys;
+
+impl Command for StorForeignKeys {
+    fn name(&self) -> &str {
+        "stor foreign-keys"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .optional(
+                "table",
+                SyntaxShape::String,
+                "only show foreign keys declared on this table",
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "List the foreign keys defined in the in-memory database."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Each row names the referencing table and columns together with the
+table and columns they point at, which makes join paths between imported
+datasets easy to discover."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Show what the orders table references",
+            example: "stor foreign-keys orders",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "foreign", "key", "references", "relation"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let table: Option<String> = call.opt(engine_state, stack, 0)?;
+        let conn = stor_connection(span)?;
+
+        let mut sql = "SELECT table_name, constraint_column_names AS columns, \
+                       referenced_table, referenced_column_names AS referenced_columns \
+                       FROM duckdb_constraints() \
+                       WHERE constraint_type = 'FOREIGN KEY'"
+            .to_string();
+        let mut params = Vec::new();
+        if let Some(table) = table {
+            sql.push_str(" AND table_name = ?");
+            params.push(Value::string(table, span));
+        }
+        sql.push_str(" ORDER BY table_name, constraint_index");
+
+        run_stor_query_params(&conn, &sql, &params, span)
+            .map(IntoPipelineData::into_pipeline_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorConstraints {});
+        test_examples(StorForeignKeys {});
+    }
+}
@@ -0,0 +1,150 @@
+use super::db::{quote_ident, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    record, Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct StorCount;
+
+impl Command for StorCount {
+    fn name(&self) -> &str {
+        "stor count"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::Nothing, Type::Int),
+                (Type::Nothing, Type::Table(vec![])),
+            ])
+            .allow_variants_without_examples(true)
+            .rest(
+                "tables",
+                SyntaxShape::String,
+                "tables to count; all tables when omitted",
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Count the rows of one or more tables, using DuckDB's metadata fast path."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Count the rows of the sales table",
+                example: "stor count sales",
+                result: None,
+            },
+            Example {
+                description: "Count the rows of every table",
+                example: "stor count",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "count", "rows", "length"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let mut tables: Vec<String> = call.rest(engine_state, stack, 0)?;
+
+        let conn = stor_connection(span)?;
+
+        // a single explicit table gives back a bare int, anything else a table
+        if tables.len() == 1 {
+            let count = count_table(&conn, &tables[0], span)?;
+            return Ok(Value::int(count, span).into_pipeline_data());
+        }
+
+        if tables.is_empty() {
+            tables = list_table_names(&conn, span)?;
+        }
+
+        let mut rows = Vec::with_capacity(tables.len());
+        for table in tables {
+            let count = count_table(&conn, &table, span)?;
+            rows.push(Value::record(
+                record! {
+                    "table" => Value::string(table, span),
+                    "rows" => Value::int(count, span),
+                },
+                span,
+            ));
+        }
+
+        Ok(Value::list(rows, span).into_pipeline_data())
+    }
+}
+
+fn count_table(conn: &duckdb::Connection, table: &str, span: Span) -> Result<i64, ShellError> {
+    conn.query_row(
+        &format!("SELECT count(*) FROM {}", quote_ident(table)),
+        [],
+        |row| row.get(0),
+    )
+    .map_err(|e| {
+        ShellError::GenericError(
+            format!("Cannot count rows of table {table}"),
+            e.to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    })
+}
+
+fn list_table_names(conn: &duckdb::Connection, span: Span) -> Result<Vec<String>, ShellError> {
+    let mut stmt = conn
+        .prepare("SELECT table_name FROM duckdb_tables()")
+        .map_err(|e| {
+            ShellError::GenericError(
+                "Failed to list tables".into(),
+                e.to_string(),
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+    let names = stmt
+        .query_map([], |row| row.get(0))
+        .and_then(|rows| rows.collect::<Result<Vec<String>, _>>())
+        .map_err(|e| {
+            ShellError::GenericError(
+                "Failed to list tables".into(),
+                e.to_string(),
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+    Ok(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorCount {});
+    }
+}
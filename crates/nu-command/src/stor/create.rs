@@ -0,0 +1,179 @@
+use super::db::{
+    insert_record, nu_value_to_duckdb_type, quote_ident, run_stor_execute, stor_connection,
+};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct StorCreate;
+
+impl Command for StorCreate {
+    fn name(&self) -> &str {
+        "stor create"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::Nothing, Type::Nothing),
+                (Type::Table(vec![]), Type::Any),
+            ])
+            .allow_variants_without_examples(true)
+            .required("table", SyntaxShape::String, "name of the table to create")
+            .named(
+                "schema",
+                SyntaxShape::Record(vec![]),
+                "explicit column types, e.g. {id: int, name: string}",
+                Some('s'),
+            )
+            .switch(
+                "if-not-exists",
+                "do nothing if the table already exists",
+                Some('e'),
+            )
+            .switch("insert", "also insert the piped rows", Some('i'))
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Create a table, inferring the schema from piped data."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Column types are inferred from the first piped record (int, float,
+bool, date, duration, binary, string) unless overridden with --schema, which
+accepts nu type names or raw DuckDB types per column. With --schema and no
+piped input the table is created empty."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Create and fill a table from piped data",
+                example: "ls | stor create files --insert",
+                result: None,
+            },
+            Example {
+                description: "Create an empty table with an explicit schema",
+                example: "stor create events --schema {id: int, at: date, payload: string}",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "create", "table", "schema", "infer"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let table: String = call.req(engine_state, stack, 0)?;
+        let schema: Option<Value> = call.get_flag(engine_state, stack, "schema")?;
+        let if_not_exists = call.has_flag("if-not-exists");
+        let insert = call.has_flag("insert");
+
+        let rows: Vec<Value> = input.into_iter().collect();
+
+        let column_defs = match &schema {
+            Some(schema) => schema
+                .as_record()?
+                .iter()
+                .map(|(col, ty)| {
+                    Ok(format!(
+                        "{} {}",
+                        quote_ident(col),
+                        duckdb_type_for(&ty.as_string()?)
+                    ))
+                })
+                .collect::<Result<Vec<_>, ShellError>>()?,
+            None => {
+                let Some(first) = rows.first() else {
+                    return Err(ShellError::GenericError(
+                        "Nothing to infer the schema from".into(),
+                        "no piped input and no --schema".into(),
+                        Some(span),
+                        Some("pipe in a table or pass --schema {col: type}".into()),
+                        Vec::new(),
+                    ));
+                };
+                first
+                    .as_record()?
+                    .iter()
+                    .map(|(col, val)| {
+                        format!("{} {}", quote_ident(col), nu_value_to_duckdb_type(val))
+                    })
+                    .collect()
+            }
+        };
+
+        let conn = stor_connection(span)?;
+        run_stor_execute(
+            &conn,
+            &format!(
+                "CREATE TABLE{} {} ({})",
+                if if_not_exists { " IF NOT EXISTS" } else { "" },
+                quote_ident(&table),
+                column_defs.join(", ")
+            ),
+            span,
+        )?;
+
+        if insert {
+            let columns: Vec<String> = match &schema {
+                Some(schema) => schema.as_record()?.cols.clone(),
+                None => rows
+                    .first()
+                    .map(|row| row.as_record().map(|record| record.cols.clone()))
+                    .transpose()?
+                    .unwrap_or_default(),
+            };
+
+            let mut inserted: i64 = 0;
+            for row in &rows {
+                insert_record(&conn, &table, &columns, row.as_record()?, span)?;
+                inserted += 1;
+            }
+            return Ok(Value::int(inserted, span).into_pipeline_data());
+        }
+
+        Ok(PipelineData::empty())
+    }
+}
+
+// Accept both nu type names and literal DuckDB types in --schema; anything
+// unrecognised passes through uppercased. Shared with `stor alter`.
+pub(super) fn duckdb_type_for(name: &str) -> String {
+    match name {
+        "int" => "BIGINT".into(),
+        "float" => "DOUBLE".into(),
+        "bool" => "BOOLEAN".into(),
+        "string" => "VARCHAR".into(),
+        "date" | "datetime" => "TIMESTAMP".into(),
+        "duration" => "INTERVAL".into(),
+        "binary" => "BLOB".into(),
+        other => other.to_uppercase(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorCreate {});
+    }
+}
@@ -0,0 +1,76 @@
+use super::db::{run_stor_query, stor_connection};
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type,
+};
+
+#[derive(Clone)]
+pub struct StorDatabases;
+
+impl Command for StorDatabases {
+    fn name(&self) -> &str {
+        "stor databases"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "List the attached databases and what file each alias maps to."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Shows the in-memory store together with every catalog brought in via
+ATTACH (through `stor exec` or `stor clone --to`), so it's always clear
+which alias points at which file. Databases attached read-only report the
+access mode in the type column DuckDB gives them."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "See what is attached",
+            example: "stor databases",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "attach", "catalog", "alias", "list"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let conn = stor_connection(span)?;
+
+        run_stor_query(
+            &conn,
+            "SELECT database_name AS name, path, type, internal \
+             FROM duckdb_databases() ORDER BY name",
+            span,
+        )
+        .map(IntoPipelineData::into_pipeline_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorDatabases {});
+    }
+}
@@ -0,0 +1,1195 @@
+use chrono::{FixedOffset, LocalResult, TimeZone, Utc};
+use duckdb::types::{OrderedMap, TimeUnit, ToSql, ToSqlOutput, Value as DuckDbValue};
+use duckdb::{Connection, Statement};
+use nu_protocol::{record, Record, ShellError, Span, Value};
+use once_cell::sync::Lazy;
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+// A single in-memory DuckDB instance shared by every `stor` command for the
+// lifetime of the shell. Commands borrow the connection through
+// `stor_connection` rather than opening their own, so data created by one
+// command is visible to the next — `ls | stor insert files` followed by
+// `stor query "select * from files"` works as expected.
+//
+// The connection lives here as a process-global rather than as a field on
+// EngineState: nu-protocol cannot depend on duckdb, EngineState is cloned
+// for every closure/background evaluation (the store must not fork with it),
+// and background threads (schedules, hooks, UDF dispatch) need the store
+// without holding an EngineState at all. The observable behaviour matches
+// upstream's SQLite-backed `stor`: one live store per shell session.
+static STOR_DB: Lazy<Result<Mutex<Connection>, duckdb::Error>> = Lazy::new(|| {
+    let conn = Connection::open_in_memory()?;
+    super::shell_relations::register_shell_relations(&conn)?;
+    apply_startup_sql(&conn)?;
+    Ok(Mutex::new(conn))
+});
+
+// Statements replayed whenever a fresh in-memory connection is opened, keyed
+// by the name of the object they recreate. This is how session-scoped objects
+// like macros survive the connection being reset.
+static STARTUP_SQL: Lazy<Mutex<Vec<(String, String)>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+// When set, DECIMAL columns convert to their exact textual form instead of a
+// lossy f64. Toggled per query by the `--decimal-as-string` flag.
+static DECIMAL_AS_STRING: AtomicBool = AtomicBool::new(false);
+
+fn set_decimal_as_string(enabled: bool) {
+    DECIMAL_AS_STRING.store(enabled, Ordering::Relaxed);
+}
+
+// Applies the per-query output conversions — `--decimal-as-string`,
+// `--nan-as-null`, and `--type-map` — and restores the defaults when the
+// command that set them returns. The settings live in process-globals the
+// value conversion reads, so without the drop they would silently keep
+// rewriting the output of every later stor command.
+pub struct OutputConversionGuard;
+
+impl OutputConversionGuard {
+    pub fn new(
+        decimal_as_string: bool,
+        nan_as_null: bool,
+        type_map: Vec<(String, String)>,
+    ) -> Self {
+        set_decimal_as_string(decimal_as_string);
+        set_nan_as_null(nan_as_null);
+        set_type_map(type_map);
+        OutputConversionGuard
+    }
+}
+
+impl Drop for OutputConversionGuard {
+    fn drop(&mut self) {
+        set_decimal_as_string(false);
+        set_nan_as_null(false);
+        set_type_map(Vec::new());
+    }
+}
+
+// The engine's ctrl-c flag, registered by commands before they run user SQL
+// so an in-flight DuckDB query can be interrupted instead of running to
+// completion. Queries issued without a registered flag simply can't be
+// cancelled mid-execution, matching the old behaviour.
+static CTRLC: Lazy<Mutex<Option<Arc<AtomicBool>>>> = Lazy::new(|| Mutex::new(None));
+
+/// Let the stor query helpers see the engine's ctrl-c flag for the duration
+/// of the calling command.
+pub fn register_ctrlc(flag: &Option<Arc<AtomicBool>>) {
+    if let Ok(mut ctrlc) = CTRLC.lock() {
+        *ctrlc = flag.clone();
+    }
+}
+
+// An optional deadline for the next queries, set per call by `--timeout`.
+// The flag records whether the most recent interruption was the deadline
+// firing (rather than ctrl-c) so the error can say "timed out".
+static QUERY_TIMEOUT: Lazy<Mutex<Option<std::time::Duration>>> = Lazy::new(|| Mutex::new(None));
+static TIMED_OUT: AtomicBool = AtomicBool::new(false);
+
+fn set_query_timeout(timeout: Option<std::time::Duration>) {
+    if let Ok(mut slot) = QUERY_TIMEOUT.lock() {
+        *slot = timeout;
+    }
+}
+
+// Arms the deadline for the duration of one command. The deadline lives in a
+// process-global the interrupt watcher reads, so dropping the guard clears it
+// again — a `--timeout` must never outlive the call that asked for it and
+// kill some unrelated later statement.
+pub struct QueryTimeoutGuard;
+
+impl QueryTimeoutGuard {
+    /// Interrupt queries that run longer than the given duration, until the
+    /// guard drops; `None` lets them run to completion.
+    pub fn new(timeout: Option<std::time::Duration>) -> Self {
+        set_query_timeout(timeout);
+        QueryTimeoutGuard
+    }
+}
+
+impl Drop for QueryTimeoutGuard {
+    fn drop(&mut self) {
+        set_query_timeout(None);
+    }
+}
+
+// Watches the registered ctrl-c flag on a background thread while a query
+// runs and interrupts the connection when it flips; dropping the watcher
+// stops the thread.
+struct InterruptWatcher {
+    done: Arc<AtomicBool>,
+}
+
+impl Drop for InterruptWatcher {
+    fn drop(&mut self) {
+        self.done.store(true, Ordering::Relaxed);
+    }
+}
+
+fn watch_for_interrupt(conn: &Connection) -> InterruptWatcher {
+    let done = Arc::new(AtomicBool::new(false));
+    TIMED_OUT.store(false, Ordering::Relaxed);
+
+    let ctrlc = CTRLC.lock().ok().and_then(|guard| guard.clone());
+    let timeout = QUERY_TIMEOUT.lock().ok().and_then(|slot| *slot);
+    if ctrlc.is_some() || timeout.is_some() {
+        let handle = conn.interrupt_handle();
+        let running = done.clone();
+        let deadline = timeout.map(|timeout| std::time::Instant::now() + timeout);
+        std::thread::spawn(move || {
+            while !running.load(Ordering::Relaxed) {
+                if let Some(ctrlc) = &ctrlc {
+                    if ctrlc.load(Ordering::Relaxed) {
+                        handle.interrupt();
+                        break;
+                    }
+                }
+                if let Some(deadline) = deadline {
+                    if std::time::Instant::now() >= deadline {
+                        TIMED_OUT.store(true, Ordering::Relaxed);
+                        handle.interrupt();
+                        break;
+                    }
+                }
+                std::thread::sleep(std::time::Duration::from_millis(50));
+            }
+        });
+    }
+
+    InterruptWatcher { done }
+}
+
+// Shows DuckDB's own percent-complete progress bar on the terminal while a
+// statement runs. duckdb-rs does not expose the progress-polling C API, so
+// instead of mirroring the percentage into an indicatif bar the engine is
+// asked to draw its own; dropping the guard turns the bar back off.
+pub(super) struct ProgressBarGuard<'a> {
+    conn: &'a Connection,
+    active: bool,
+}
+
+impl<'a> ProgressBarGuard<'a> {
+    pub fn new(conn: &'a Connection, enabled: bool, span: Span) -> Result<Self, ShellError> {
+        if enabled {
+            conn.execute_batch(
+                "SET enable_progress_bar = true; SET progress_bar_time = 200;",
+            )
+            .map_err(|e| {
+                ShellError::GenericError(
+                    "Failed to enable the progress bar".into(),
+                    e.to_string(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                )
+            })?;
+        }
+        Ok(ProgressBarGuard {
+            conn,
+            active: enabled,
+        })
+    }
+}
+
+impl Drop for ProgressBarGuard<'_> {
+    fn drop(&mut self) {
+        if self.active {
+            let _ = self.conn.execute_batch("SET enable_progress_bar = false;");
+        }
+    }
+}
+
+fn timed_out() -> bool {
+    TIMED_OUT.load(Ordering::Relaxed)
+}
+
+fn was_cancelled() -> bool {
+    CTRLC
+        .lock()
+        .ok()
+        .and_then(|guard| guard.clone())
+        .map(|flag| flag.load(Ordering::Relaxed))
+        .unwrap_or(false)
+}
+
+// When set, non-finite floats (NaN, ±Inf) in query results become nothing
+// instead of leaking into pipelines where they break sorting and JSON export.
+static NAN_AS_NULL: AtomicBool = AtomicBool::new(false);
+
+fn set_nan_as_null(enabled: bool) {
+    NAN_AS_NULL.store(enabled, Ordering::Relaxed);
+}
+
+// UTC offset (in seconds) of the session's TimeZone setting, refreshed before
+// each query so TIMESTAMPTZ results can carry the offset users asked for via
+// `SET TimeZone`. DuckDB hands timestamps over as absolute instants, so the
+// offset only changes how the instant is presented, never the instant itself.
+static SESSION_OFFSET_SECS: AtomicI64 = AtomicI64::new(0);
+
+pub(super) fn refresh_session_offset(conn: &Connection) {
+    // local wall time minus UTC wall time of the same instant is the offset
+    let offset: Result<i64, _> = conn.query_row(
+        "SELECT (epoch(now()::timestamp) - epoch(now() AT TIME ZONE 'UTC'))::BIGINT",
+        [],
+        |row| row.get(0),
+    );
+    if let Ok(offset) = offset {
+        SESSION_OFFSET_SECS.store(offset, Ordering::Relaxed);
+    }
+}
+
+// Per-query overrides of the default type mapping, keyed by the DuckDB source
+// type ("blob", "decimal", "timestamp", ...) and naming the nu type the value
+// should be coerced to instead ("string", "int", "float", "nothing").
+static TYPE_MAP: Lazy<Mutex<Vec<(String, String)>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+fn set_type_map(overrides: Vec<(String, String)>) {
+    if let Ok(mut map) = TYPE_MAP.lock() {
+        *map = overrides;
+    }
+}
+
+/// Record a statement to be replayed on every fresh in-memory connection.
+pub fn record_startup_sql(key: &str, sql: &str) {
+    if let Ok(mut stmts) = STARTUP_SQL.lock() {
+        stmts.push((key.to_string(), sql.to_string()));
+    }
+}
+
+/// Stop replaying the statements recorded under the given key.
+pub fn forget_startup_sql(key: &str) {
+    if let Ok(mut stmts) = STARTUP_SQL.lock() {
+        stmts.retain(|(k, _)| k != key);
+    }
+}
+
+fn apply_startup_sql(conn: &Connection) -> Result<(), duckdb::Error> {
+    if let Ok(stmts) = STARTUP_SQL.lock() {
+        for (_, sql) in stmts.iter() {
+            conn.execute_batch(sql)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn stor_connection(call_span: Span) -> Result<MutexGuard<'static, Connection>, ShellError> {
+    let db = STOR_DB.as_ref().map_err(|e| {
+        ShellError::GenericError(
+            "Failed to open in-memory DuckDB database".into(),
+            e.to_string(),
+            Some(call_span),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    db.lock().map_err(|e| {
+        ShellError::GenericError(
+            "Failed to lock the stor database connection".into(),
+            e.to_string(),
+            Some(call_span),
+            None,
+            Vec::new(),
+        )
+    })
+}
+
+/// Run a query against the stor database and collect the result rows into a
+/// nu list of records.
+pub fn run_stor_query(
+    conn: &Connection,
+    sql: &str,
+    call_span: Span,
+) -> Result<Value, ShellError> {
+    if let Some(cached) = super::cache::lookup(sql) {
+        return Ok(cached);
+    }
+
+    refresh_session_offset(conn);
+
+    let stmt = conn.prepare(sql).map_err(|e| {
+        ShellError::GenericError(
+            "Failed to prepare DuckDB statement".into(),
+            e.to_string(),
+            Some(call_span),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    let started = std::time::Instant::now();
+    let watcher = watch_for_interrupt(conn);
+    let result = prepared_statement_to_nu_list(stmt, call_span).map_err(|e| {
+        if timed_out() {
+            ShellError::GenericError(
+                "Query timed out".into(),
+                "interrupted after exceeding --timeout".into(),
+                Some(call_span),
+                None,
+                Vec::new(),
+            )
+        } else if was_cancelled() {
+            ShellError::GenericError(
+                "Query cancelled".into(),
+                "interrupted by ctrl-c".into(),
+                Some(call_span),
+                None,
+                Vec::new(),
+            )
+        } else {
+            ShellError::GenericError(
+                "Failed to query stor database".into(),
+                e.to_string(),
+                Some(call_span),
+                None,
+                Vec::new(),
+            )
+        }
+    });
+    drop(watcher);
+    let rows = result.as_ref().ok().and_then(|value| match value {
+        Value::List { vals, .. } => Some(vals.len() as i64),
+        _ => None,
+    });
+    super::hooks::notify_query_finished(sql, started.elapsed(), result.is_ok());
+    super::history::record_statement(sql, started.elapsed(), rows, result.is_ok());
+
+    if let Ok(value) = &result {
+        super::cache::store(sql, value);
+    }
+
+    result
+}
+
+/// Run a statement for its side effects, returning the number of affected rows.
+pub fn run_stor_execute(
+    conn: &Connection,
+    sql: &str,
+    call_span: Span,
+) -> Result<usize, ShellError> {
+    run_stor_execute_redacted(conn, sql, sql, call_span)
+}
+
+/// Like [`run_stor_execute`], but records `logged` — a redacted rendering of
+/// the statement — in `stor history` and hands it to the completion hooks
+/// instead of the SQL actually run. Statements carrying credentials (CREATE
+/// SECRET and friends) go through here so their values never end up in the
+/// history log or in hook closures.
+pub(super) fn run_stor_execute_redacted(
+    conn: &Connection,
+    sql: &str,
+    logged: &str,
+    call_span: Span,
+) -> Result<usize, ShellError> {
+    let started = std::time::Instant::now();
+    let watcher = watch_for_interrupt(conn);
+    let result = conn.execute(sql, []).map_err(|e| {
+        if timed_out() {
+            ShellError::GenericError(
+                "Query timed out".into(),
+                "interrupted after exceeding --timeout".into(),
+                Some(call_span),
+                None,
+                Vec::new(),
+            )
+        } else if was_cancelled() {
+            ShellError::GenericError(
+                "Query cancelled".into(),
+                "interrupted by ctrl-c".into(),
+                Some(call_span),
+                None,
+                Vec::new(),
+            )
+        } else {
+            ShellError::GenericError(
+                "Failed to execute DuckDB statement".into(),
+                e.to_string(),
+                Some(call_span),
+                None,
+                Vec::new(),
+            )
+        }
+    });
+    drop(watcher);
+    // statements run for their side effects may have written; drop the
+    // cached query results so later reads can't return pre-write rows
+    if result.is_ok() {
+        super::cache::invalidate();
+    }
+    super::hooks::notify_query_finished(logged, started.elapsed(), result.is_ok());
+    super::history::record_statement(
+        logged,
+        started.elapsed(),
+        result.as_ref().ok().map(|rows| *rows as i64),
+        result.is_ok(),
+    );
+
+    result
+}
+
+/// Run a query with bound parameters, collecting the result rows into a nu
+/// list of records. Parameterized queries bypass the result cache: the cache
+/// is keyed on SQL text alone and must not serve rows bound to other values.
+pub fn run_stor_query_params(
+    conn: &Connection,
+    sql: &str,
+    params: &[Value],
+    call_span: Span,
+) -> Result<Value, ShellError> {
+    refresh_session_offset(conn);
+
+    let mut stmt = conn.prepare(sql).map_err(|e| {
+        ShellError::GenericError(
+            "Failed to prepare DuckDB statement".into(),
+            e.to_string(),
+            Some(call_span),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    let wrapped: Vec<NuValueParam> = params.iter().map(NuValueParam).collect();
+    let refs: Vec<&dyn ToSql> = wrapped.iter().map(|p| p as &dyn ToSql).collect();
+
+    let started = std::time::Instant::now();
+    let watcher = watch_for_interrupt(conn);
+    let result = (|| -> Result<Value, duckdb::Error> {
+        let mut rows = stmt.query(&refs[..])?;
+        let column_names = rows
+            .as_ref()
+            .map(|stmt| stmt.column_names())
+            .unwrap_or_default();
+
+        let mut row_values = vec![];
+        while let Some(row) = rows.next()? {
+            row_values.push(convert_duckdb_row_to_nu_value(row, call_span, &column_names));
+        }
+        Ok(Value::list(row_values, call_span))
+    })()
+    .map_err(|e| {
+        if timed_out() {
+            ShellError::GenericError(
+                "Query timed out".into(),
+                "interrupted after exceeding --timeout".into(),
+                Some(call_span),
+                None,
+                Vec::new(),
+            )
+        } else if was_cancelled() {
+            ShellError::GenericError(
+                "Query cancelled".into(),
+                "interrupted by ctrl-c".into(),
+                Some(call_span),
+                None,
+                Vec::new(),
+            )
+        } else {
+            ShellError::GenericError(
+                "Failed to query stor database".into(),
+                e.to_string(),
+                Some(call_span),
+                None,
+                Vec::new(),
+            )
+        }
+    });
+    drop(watcher);
+    // parameterized statements can be DML too (EXECUTE of a prepared INSERT,
+    // for instance); anything that isn't a plain SELECT may have written
+    if result.is_ok() && !sql.trim_start().to_lowercase().starts_with("select") {
+        super::cache::invalidate();
+    }
+    let rows = result.as_ref().ok().and_then(|value| match value {
+        Value::List { vals, .. } => Some(vals.len() as i64),
+        _ => None,
+    });
+    super::hooks::notify_query_finished(sql, started.elapsed(), result.is_ok());
+    super::history::record_statement(sql, started.elapsed(), rows, result.is_ok());
+
+    result
+}
+
+/// Like [`run_stor_query`] but wraps the rows together with the declared
+/// result schema, returning `{schema: [[column type]], rows: [...]}` so
+/// scripts can make type-aware decisions without re-describing the query.
+pub fn run_stor_query_with_schema(
+    conn: &Connection,
+    sql: &str,
+    call_span: Span,
+) -> Result<Value, ShellError> {
+    refresh_session_offset(conn);
+
+    let stmt = conn.prepare(sql).map_err(|e| {
+        ShellError::GenericError(
+            "Failed to prepare DuckDB statement".into(),
+            e.to_string(),
+            Some(call_span),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    prepared_statement_to_nu_table(stmt, call_span).map_err(|e| {
+        ShellError::GenericError(
+            "Failed to query stor database".into(),
+            e.to_string(),
+            Some(call_span),
+            None,
+            Vec::new(),
+        )
+    })
+}
+
+fn prepared_statement_to_nu_table(
+    mut stmt: Statement,
+    call_span: Span,
+) -> Result<Value, duckdb::Error> {
+    let mut rows = stmt.query([])?;
+
+    let (column_names, column_types) = match rows.as_ref() {
+        Some(stmt) => {
+            let names = stmt.column_names();
+            let types = (0..names.len())
+                .map(|i| format!("{:?}", stmt.column_type(i)))
+                .collect::<Vec<_>>();
+            (names, types)
+        }
+        None => (Vec::new(), Vec::new()),
+    };
+
+    let schema = column_names
+        .iter()
+        .zip(&column_types)
+        .map(|(col, ty)| {
+            Value::record(
+                record! {
+                    "column" => Value::string(col.clone(), call_span),
+                    "type" => Value::string(ty.clone(), call_span),
+                },
+                call_span,
+            )
+        })
+        .collect();
+
+    let mut row_values = vec![];
+    while let Some(row) = rows.next()? {
+        row_values.push(convert_duckdb_row_to_nu_value(row, call_span, &column_names));
+    }
+
+    Ok(Value::record(
+        record! {
+            "schema" => Value::list(schema, call_span),
+            "rows" => Value::list(row_values, call_span),
+        },
+        call_span,
+    ))
+}
+
+pub fn prepared_statement_to_nu_list(
+    mut stmt: Statement,
+    call_span: Span,
+) -> Result<Value, duckdb::Error> {
+    let mut rows = stmt.query([])?;
+
+    // DuckDB only exposes result column names once a statement has been
+    // executed, so read them back off the now-executed statement instead of
+    // running it a second time just for the metadata
+    let column_names = rows
+        .as_ref()
+        .map(|stmt| stmt.column_names())
+        .unwrap_or_default();
+
+    // we collect all rows before returning them. Not ideal but it's hard/impossible to return a stream from a CustomValue
+    let mut row_values = vec![];
+
+    while let Some(row) = rows.next()? {
+        row_values.push(convert_duckdb_row_to_nu_value(row, call_span, &column_names));
+    }
+
+    Ok(Value::list(row_values, call_span))
+}
+
+pub fn convert_duckdb_row_to_nu_value(
+    row: &duckdb::Row,
+    span: Span,
+    column_names: &[String],
+) -> Value {
+    let mut record = Record::new();
+
+    for (i, col) in column_names.iter().enumerate() {
+        let val = row.get::<_, DuckDbValue>(i).unwrap_or(DuckDbValue::Null);
+        record.push(col.clone(), convert_duckdb_value_to_nu_value(val, span));
+    }
+
+    Value::record(record, span)
+}
+
+/// Convert one DuckDB result value into the closest nu value.
+///
+/// UUID columns need no arm of their own: DuckDB's result interface renders
+/// them as canonical hyphenated text, so they arrive here as `Text`. In the
+/// other direction a nu string in that form implicitly casts into a UUID
+/// column, so UUID-keyed tables round-trip through `stor` commands as strings.
+pub fn convert_duckdb_value_to_nu_value(value: DuckDbValue, span: Span) -> Value {
+    let kind = duckdb_type_name(&value);
+    let converted = match value {
+        DuckDbValue::Null => Value::nothing(span),
+        DuckDbValue::Boolean(b) => Value::bool(b, span),
+        DuckDbValue::TinyInt(i) => Value::int(i as i64, span),
+        DuckDbValue::SmallInt(i) => Value::int(i as i64, span),
+        DuckDbValue::Int(i) => Value::int(i as i64, span),
+        DuckDbValue::BigInt(i) => Value::int(i, span),
+        DuckDbValue::UTinyInt(i) => Value::int(i as i64, span),
+        DuckDbValue::USmallInt(i) => Value::int(i as i64, span),
+        DuckDbValue::UInt(i) => Value::int(i as i64, span),
+        // 128-bit and unsigned 64-bit values only fit a nu int when they fit
+        // in i64; beyond that, keep the exact digits as a string rather than
+        // failing the whole row or silently wrapping
+        DuckDbValue::HugeInt(i) => match i64::try_from(i) {
+            Ok(i) => Value::int(i, span),
+            Err(_) => Value::string(i.to_string(), span),
+        },
+        DuckDbValue::UBigInt(i) => match i64::try_from(i) {
+            Ok(i) => Value::int(i, span),
+            Err(_) => Value::string(i.to_string(), span),
+        },
+        DuckDbValue::Float(f) => float_to_nu(f as f64, span),
+        DuckDbValue::Double(f) => float_to_nu(f, span),
+        DuckDbValue::Text(s) => Value::string(s, span),
+        DuckDbValue::Blob(b) => Value::binary(b, span),
+        DuckDbValue::Timestamp(unit, v) => micros_to_nu_date(unit.to_micros(v), span),
+        DuckDbValue::Date32(days) => micros_to_nu_date(days as i64 * 86_400_000_000, span),
+        // a TIME is an offset from midnight, which maps naturally onto a
+        // nu duration (and stays filter/sortable, unlike a formatted string)
+        DuckDbValue::Time64(unit, v) => Value::duration(unit.to_micros(v) * 1_000, span),
+        DuckDbValue::Decimal(d) => {
+            if DECIMAL_AS_STRING.load(Ordering::Relaxed) {
+                Value::string(d.to_string(), span)
+            } else {
+                // go through the decimal's text form rather than pulling in
+                // rust_decimal's ToPrimitive just for this conversion
+                float_to_nu(d.to_string().parse().unwrap_or(f64::NAN), span)
+            }
+        }
+        // LIST and fixed-size ARRAY both map onto a nu list, recursing so
+        // nested lists work too
+        DuckDbValue::List(items) | DuckDbValue::Array(items) => Value::list(
+            items
+                .into_iter()
+                .map(|item| convert_duckdb_value_to_nu_value(item, span))
+                .collect(),
+            span,
+        ),
+        DuckDbValue::Struct(fields) => {
+            let mut record = Record::new();
+            for (name, field) in fields.iter() {
+                record.push(
+                    name.clone(),
+                    convert_duckdb_value_to_nu_value(field.clone(), span),
+                );
+            }
+            Value::record(record, span)
+        }
+        DuckDbValue::Map(entries) => {
+            let mut record = Record::new();
+            for (key, val) in entries.iter() {
+                // record columns must be strings; non-string keys (ints,
+                // dates, ...) are keyed by their nu string rendering
+                let key = match key {
+                    DuckDbValue::Text(s) => s.clone(),
+                    other => convert_duckdb_value_to_nu_value(other.clone(), span)
+                        .as_string()
+                        .unwrap_or_else(|_| format!("{other:?}")),
+                };
+                record.push(key, convert_duckdb_value_to_nu_value(val.clone(), span));
+            }
+            Value::record(record, span)
+        }
+        DuckDbValue::Enum(label) => Value::string(label, span),
+        // DuckDB splits intervals into months/days/nanos; nu durations are a
+        // flat nanosecond count, so flatten with DuckDB's own calendar
+        // conventions (30-day months, 24-hour days)
+        DuckDbValue::Interval {
+            months,
+            days,
+            nanos,
+        } => {
+            let day_nanos = 86_400_000_000_000i64;
+            Value::duration(
+                (months as i64 * 30 + days as i64).saturating_mul(day_nanos) + nanos,
+                span,
+            )
+        }
+        // the remaining DuckDB types (unions, ...) don't have a natural nu
+        // mapping yet, fall back to their debug form
+        other => Value::string(format!("{other:?}"), span),
+    };
+
+    apply_type_override(kind, converted, span)
+}
+
+fn float_to_nu(f: f64, span: Span) -> Value {
+    if !f.is_finite() && NAN_AS_NULL.load(Ordering::Relaxed) {
+        Value::nothing(span)
+    } else {
+        Value::float(f, span)
+    }
+}
+
+fn duckdb_type_name(value: &DuckDbValue) -> &'static str {
+    match value {
+        DuckDbValue::Null => "null",
+        DuckDbValue::Boolean(_) => "bool",
+        DuckDbValue::TinyInt(_)
+        | DuckDbValue::SmallInt(_)
+        | DuckDbValue::Int(_)
+        | DuckDbValue::BigInt(_)
+        | DuckDbValue::HugeInt(_)
+        | DuckDbValue::UTinyInt(_)
+        | DuckDbValue::USmallInt(_)
+        | DuckDbValue::UInt(_)
+        | DuckDbValue::UBigInt(_) => "int",
+        DuckDbValue::Float(_) | DuckDbValue::Double(_) => "float",
+        DuckDbValue::Decimal(_) => "decimal",
+        DuckDbValue::Text(_) => "text",
+        DuckDbValue::Blob(_) => "blob",
+        DuckDbValue::Timestamp(..) => "timestamp",
+        DuckDbValue::Date32(_) => "date",
+        DuckDbValue::Time64(..) => "time",
+        DuckDbValue::Interval { .. } => "interval",
+        DuckDbValue::List(_) | DuckDbValue::Array(_) => "list",
+        DuckDbValue::Struct(_) => "struct",
+        DuckDbValue::Map(_) => "map",
+        DuckDbValue::Enum(_) => "enum",
+        _ => "other",
+    }
+}
+
+// Apply any `--type-map` override registered for this source type, coercing
+// the already-converted value into the requested nu type.
+fn apply_type_override(kind: &str, value: Value, span: Span) -> Value {
+    let target = match TYPE_MAP.lock() {
+        Ok(map) => match map.iter().find(|(from, _)| from == kind) {
+            Some((_, to)) => to.clone(),
+            None => return value,
+        },
+        Err(_) => return value,
+    };
+
+    match target.as_str() {
+        "string" => match &value {
+            Value::String { .. } => value,
+            other => Value::string(
+                other.as_string().unwrap_or_else(|_| format!("{other:?}")),
+                span,
+            ),
+        },
+        "int" => match &value {
+            Value::Float { val, .. } => Value::int(*val as i64, span),
+            Value::Date { val, .. } => Value::int(val.timestamp_micros(), span),
+            Value::Duration { val, .. } => Value::int(*val, span),
+            Value::Bool { val, .. } => Value::int(*val as i64, span),
+            _ => value,
+        },
+        "float" => match &value {
+            Value::Int { val, .. } => Value::float(*val as f64, span),
+            _ => value,
+        },
+        "nothing" => Value::nothing(span),
+        // unknown targets leave the value as-is rather than dropping data
+        _ => value,
+    }
+}
+
+// DuckDB hands timestamps over as an integer offset from the unix epoch; nu
+// dates are chrono datetimes, so go through chrono's UTC conversion.
+fn micros_to_nu_date(micros: i64, span: Span) -> Value {
+    let offset = FixedOffset::east_opt(SESSION_OFFSET_SECS.load(Ordering::Relaxed) as i32)
+        .unwrap_or_else(|| FixedOffset::east_opt(0).expect("UTC offset is valid"));
+    match Utc.timestamp_micros(micros) {
+        LocalResult::Single(dt) => Value::date(dt.with_timezone(&offset), span),
+        // chrono can't represent timestamps this far out; keep the raw offset
+        // visible instead of erroring the whole row
+        _ => Value::string(format!("{micros} µs since epoch"), span),
+    }
+}
+
+/// Borrowable wrapper letting a nu [`Value`] be passed wherever duckdb
+/// expects a bindable parameter, e.g. `stmt.execute([&NuValueParam(&value)])`.
+pub struct NuValueParam<'a>(pub &'a Value);
+
+impl ToSql for NuValueParam<'_> {
+    fn to_sql(&self) -> duckdb::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::Owned(convert_nu_value_to_db_param(self.0)))
+    }
+}
+
+/// Convert a nu value into an owned DuckDB value suitable for parameter
+/// binding; the inverse of [`convert_duckdb_value_to_nu_value`].
+pub fn convert_nu_value_to_db_param(value: &Value) -> DuckDbValue {
+    match value {
+        Value::Nothing { .. } => DuckDbValue::Null,
+        Value::Bool { val, .. } => DuckDbValue::Boolean(*val),
+        Value::Int { val, .. } => DuckDbValue::BigInt(*val),
+        Value::Filesize { val, .. } => DuckDbValue::BigInt(*val),
+        Value::Float { val, .. } => DuckDbValue::Double(*val),
+        Value::String { val, .. } => DuckDbValue::Text(val.clone()),
+        Value::Binary { val, .. } => DuckDbValue::Blob(val.clone()),
+        Value::Date { val, .. } => {
+            DuckDbValue::Timestamp(TimeUnit::Microsecond, val.timestamp_micros())
+        }
+        Value::Duration { val, .. } => DuckDbValue::Interval {
+            months: 0,
+            days: 0,
+            nanos: *val,
+        },
+        Value::List { vals, .. } => {
+            DuckDbValue::List(vals.iter().map(convert_nu_value_to_db_param).collect())
+        }
+        Value::Record { val, .. } => DuckDbValue::Struct(OrderedMap::from(
+            val.iter()
+                .map(|(col, val)| (col.clone(), convert_nu_value_to_db_param(val)))
+                .collect::<Vec<_>>(),
+        )),
+        // everything else (ranges, closures, ...) has no DuckDB shape; bind
+        // its string rendering so the query still sees something sensible
+        other => DuckDbValue::Text(
+            other
+                .as_string()
+                .unwrap_or_else(|_| format!("{other:?}")),
+        ),
+    }
+}
+
+/// Split a SQL script into statements on `;`, ignoring semicolons inside
+/// single-quoted strings, `-- line` comments and `/* block */` comments.
+/// Empty fragments are dropped.
+pub(super) fn split_sql_statements(sql: &str) -> Vec<String> {
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    let mut chars = sql.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            if c == '\'' {
+                in_string = false;
+            }
+            current.push(c);
+        } else if c == '\'' {
+            in_string = true;
+            current.push(c);
+        } else if c == '-' && chars.peek() == Some(&'-') {
+            for c in chars.by_ref() {
+                if c == '\n' {
+                    current.push(c);
+                    break;
+                }
+            }
+        } else if c == '/' && chars.peek() == Some(&'*') {
+            chars.next();
+            while let Some(c) = chars.next() {
+                if c == '*' && chars.peek() == Some(&'/') {
+                    chars.next();
+                    break;
+                }
+            }
+        } else if c == ';' {
+            if !current.trim().is_empty() {
+                statements.push(current.trim().to_string());
+            }
+            current.clear();
+        } else {
+            current.push(c);
+        }
+    }
+    if !current.trim().is_empty() {
+        statements.push(current.trim().to_string());
+    }
+
+    statements
+}
+
+/// Quote an identifier so it can be safely interpolated into SQL text.
+pub fn quote_ident(ident: &str) -> String {
+    format!("\"{}\"", ident.replace('"', "\"\""))
+}
+
+/// Make sure the metadata table backing `stor comment` exists.
+pub fn ensure_comments_table(conn: &Connection, call_span: Span) -> Result<(), ShellError> {
+    run_stor_execute(
+        conn,
+        "CREATE TABLE IF NOT EXISTS nu_stor_comments (
+            table_name VARCHAR,
+            column_name VARCHAR,
+            comment VARCHAR
+        )",
+        call_span,
+    )?;
+    Ok(())
+}
+
+/// Pick a DuckDB column type able to hold the given nu value.
+pub fn nu_value_to_duckdb_type(value: &Value) -> &'static str {
+    match value {
+        Value::Int { .. } => "BIGINT",
+        Value::Filesize { .. } => "BIGINT",
+        Value::Float { .. } => "DOUBLE",
+        Value::Bool { .. } => "BOOLEAN",
+        Value::Binary { .. } => "BLOB",
+        Value::Date { .. } => "TIMESTAMP",
+        Value::Duration { .. } => "INTERVAL",
+        // everything else round-trips through its string form for now
+        _ => "VARCHAR",
+    }
+}
+
+/// Create `table_name` if it does not exist yet, with one column per field of
+/// the given record, and return the column order used for inserts.
+pub fn ensure_table_for_record(
+    conn: &Connection,
+    table_name: &str,
+    record: &Record,
+    call_span: Span,
+) -> Result<Vec<String>, ShellError> {
+    let column_defs = record
+        .iter()
+        .map(|(col, val)| format!("{} {}", quote_ident(col), nu_value_to_duckdb_type(val)))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    run_stor_execute(
+        conn,
+        &format!(
+            "CREATE TABLE IF NOT EXISTS {} ({})",
+            quote_ident(table_name),
+            column_defs
+        ),
+        call_span,
+    )?;
+
+    Ok(record.cols.clone())
+}
+
+/// Column names of a table in declaration order, straight from the catalog;
+/// empty when no table of that name exists.
+pub fn table_columns(
+    conn: &Connection,
+    table: &str,
+    call_span: Span,
+) -> Result<Vec<String>, ShellError> {
+    let mut columns = Vec::new();
+    let result = (|| -> Result<(), duckdb::Error> {
+        let mut stmt = conn.prepare(
+            "SELECT column_name FROM duckdb_columns() WHERE table_name = ? ORDER BY column_index",
+        )?;
+        let mut rows = stmt.query([table])?;
+        while let Some(row) = rows.next()? {
+            columns.push(row.get(0)?);
+        }
+        Ok(())
+    })();
+
+    result.map_err(|e| {
+        ShellError::GenericError(
+            format!("Failed to read columns of {table}"),
+            e.to_string(),
+            Some(call_span),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    Ok(columns)
+}
+
+/// Insert one record into `table_name` by column name, binding each field as
+/// a real prepared-statement parameter.
+pub fn insert_record(
+    conn: &Connection,
+    table_name: &str,
+    columns: &[String],
+    record: &Record,
+    call_span: Span,
+) -> Result<(), ShellError> {
+    let nothing = Value::nothing(call_span);
+    let values = columns
+        .iter()
+        .map(|col| {
+            record
+                .iter()
+                .find(|(k, _)| *k == col)
+                .map(|(_, v)| v)
+                .unwrap_or(&nothing)
+        })
+        .collect::<Vec<_>>();
+
+    let placeholders = vec!["?"; values.len()].join(", ");
+    let wrapped: Vec<NuValueParam> = values.into_iter().map(NuValueParam).collect();
+    let refs: Vec<&dyn ToSql> = wrapped.iter().map(|p| p as &dyn ToSql).collect();
+
+    // name the columns instead of relying on VALUES position: the table may
+    // predate this insert with a different declared column order
+    let column_list = columns
+        .iter()
+        .map(|col| quote_ident(col))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    conn.execute(
+        &format!(
+            "INSERT INTO {} ({column_list}) VALUES ({placeholders})",
+            quote_ident(table_name)
+        ),
+        &refs[..],
+    )
+    .map_err(|e| {
+        ShellError::GenericError(
+            format!("Failed to insert into {table_name}"),
+            e.to_string(),
+            Some(call_span),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    // inserts bypass run_stor_execute, so drop the cached results here too
+    super::cache::invalidate();
+
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_statements_on_semicolons() {
+        assert_eq!(
+            split_sql_statements("CREATE TABLE t (a INT); INSERT INTO t VALUES (1);"),
+            vec!["CREATE TABLE t (a INT)", "INSERT INTO t VALUES (1)"]
+        );
+    }
+
+    #[test]
+    fn split_keeps_semicolons_inside_strings() {
+        assert_eq!(
+            split_sql_statements("SELECT 'a;b'; SELECT 2"),
+            vec!["SELECT 'a;b'", "SELECT 2"]
+        );
+    }
+
+    #[test]
+    fn split_ignores_semicolons_in_comments() {
+        assert_eq!(
+            split_sql_statements("SELECT 1 -- trailing; comment\n; SELECT /* not; here */ 2"),
+            vec!["SELECT 1", "SELECT  2"]
+        );
+    }
+
+    #[test]
+    fn split_keeps_a_trailing_statement_and_drops_empty_fragments() {
+        assert_eq!(
+            split_sql_statements(";;  ;SELECT 1"),
+            vec!["SELECT 1"]
+        );
+    }
+
+    #[test]
+    fn quote_ident_doubles_embedded_quotes() {
+        assert_eq!(quote_ident("orders"), "\"orders\"");
+        assert_eq!(quote_ident("odd\"name"), "\"odd\"\"name\"");
+    }
+
+    #[test]
+    fn hugeint_and_ubigint_fall_back_to_strings_past_i64() {
+        let span = Span::test_data();
+        assert_eq!(
+            convert_duckdb_value_to_nu_value(DuckDbValue::HugeInt(42), span),
+            Value::test_int(42)
+        );
+        assert_eq!(
+            convert_duckdb_value_to_nu_value(DuckDbValue::HugeInt(i64::MAX as i128 + 1), span),
+            Value::test_string("9223372036854775808")
+        );
+        assert_eq!(
+            convert_duckdb_value_to_nu_value(DuckDbValue::UBigInt(u64::MAX), span),
+            Value::test_string("18446744073709551615")
+        );
+    }
+
+    #[test]
+    fn intervals_and_times_become_durations() {
+        let span = Span::test_data();
+        // one 30-day month, two days, and three nanoseconds
+        assert_eq!(
+            convert_duckdb_value_to_nu_value(
+                DuckDbValue::Interval {
+                    months: 1,
+                    days: 2,
+                    nanos: 3,
+                },
+                span
+            ),
+            Value::duration(32 * 86_400_000_000_000 + 3, span)
+        );
+        assert_eq!(
+            convert_duckdb_value_to_nu_value(DuckDbValue::Time64(TimeUnit::Microsecond, 5), span),
+            Value::duration(5_000, span)
+        );
+    }
+
+    #[test]
+    fn lists_convert_recursively() {
+        let span = Span::test_data();
+        let value = convert_duckdb_value_to_nu_value(
+            DuckDbValue::List(vec![
+                DuckDbValue::Int(1),
+                DuckDbValue::List(vec![DuckDbValue::Text("x".into())]),
+            ]),
+            span,
+        );
+        assert_eq!(
+            value,
+            Value::list(
+                vec![
+                    Value::test_int(1),
+                    Value::list(vec![Value::test_string("x")], span),
+                ],
+                span
+            )
+        );
+    }
+
+    #[test]
+    fn nu_params_bind_as_native_duckdb_values() {
+        assert_eq!(
+            convert_nu_value_to_db_param(&Value::test_int(7)),
+            DuckDbValue::BigInt(7)
+        );
+        assert_eq!(
+            convert_nu_value_to_db_param(&Value::test_string("hi")),
+            DuckDbValue::Text("hi".into())
+        );
+        assert_eq!(
+            convert_nu_value_to_db_param(&Value::test_nothing()),
+            DuckDbValue::Null
+        );
+        assert_eq!(
+            convert_nu_value_to_db_param(&Value::duration(1_500, Span::test_data())),
+            DuckDbValue::Interval {
+                months: 0,
+                days: 0,
+                nanos: 1_500,
+            }
+        );
+        assert_eq!(
+            convert_nu_value_to_db_param(&Value::list(
+                vec![Value::test_int(1), Value::test_int(2)],
+                Span::test_data()
+            )),
+            DuckDbValue::List(vec![DuckDbValue::BigInt(1), DuckDbValue::BigInt(2)])
+        );
+    }
+}
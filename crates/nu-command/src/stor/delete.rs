@@ -0,0 +1,115 @@
+use super::db::{quote_ident, run_stor_execute, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct StorDelete;
+
+impl Command for StorDelete {
+    fn name(&self) -> &str {
+        "stor delete"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Int)])
+            .allow_variants_without_examples(true)
+            .required("table", SyntaxShape::String, "table to delete rows from")
+            .named(
+                "where",
+                SyntaxShape::String,
+                "SQL condition selecting the rows to delete",
+                Some('w'),
+            )
+            .switch("all", "delete every row in the table", Some('a'))
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Delete rows from a table, returning the number of deleted rows."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "As a safety check, either --where or --all must be given; a bare
+`stor delete table` refuses to run rather than silently emptying the table."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Delete old log rows",
+                example: r#"stor delete logs --where "level = 'debug'""#,
+                result: None,
+            },
+            Example {
+                description: "Delete every row",
+                example: "stor delete scratch --all",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "delete", "remove", "rows"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let table: String = call.req(engine_state, stack, 0)?;
+        let condition: Option<String> = call.get_flag(engine_state, stack, "where")?;
+        let all = call.has_flag("all");
+
+        let sql = match (&condition, all) {
+            (Some(condition), false) => {
+                format!("DELETE FROM {} WHERE {condition}", quote_ident(&table))
+            }
+            (None, true) => format!("DELETE FROM {}", quote_ident(&table)),
+            (Some(_), true) => {
+                return Err(ShellError::GenericError(
+                    "--where and --all are mutually exclusive".into(),
+                    "pick one".into(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                ))
+            }
+            (None, false) => {
+                return Err(ShellError::GenericError(
+                    "Refusing to delete without a condition".into(),
+                    "no --where given".into(),
+                    Some(span),
+                    Some("pass --where <condition>, or --all to empty the table".into()),
+                    Vec::new(),
+                ))
+            }
+        };
+
+        let conn = stor_connection(span)?;
+        let deleted = run_stor_execute(&conn, &sql, span)?;
+
+        Ok(Value::int(deleted as i64, span).into_pipeline_data())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorDelete {});
+    }
+}
@@ -0,0 +1,77 @@
+use super::db::{run_stor_query, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct StorDescribeQuery;
+
+impl Command for StorDescribeQuery {
+    fn name(&self) -> &str {
+        "stor describe-query"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .required(
+                "query",
+                SyntaxShape::String,
+                "SELECT statement to describe",
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Show the columns and types a query would produce, without running it."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Wraps DuckDB's DESCRIBE: only the query plan is built, so the shape
+of an expensive scan can be inspected before committing to it. For the
+rows together with their schema, see `stor query --with-schema`."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Preview the shape of a parquet scan",
+            example: r#"stor describe-query "select * from read_parquet('big/*.parquet')""#,
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "describe", "schema", "columns", "types"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let query: String = call.req(engine_state, stack, 0)?;
+
+        let conn = stor_connection(span)?;
+        run_stor_query(&conn, &format!("DESCRIBE {query}"), span)
+            .map(IntoPipelineData::into_pipeline_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorDescribeQuery {});
+    }
+}
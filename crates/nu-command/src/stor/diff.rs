@@ -0,0 +1,258 @@
+use super::db::{
+    ensure_table_for_record, insert_record, quote_ident, register_ctrlc, run_stor_execute,
+    run_stor_query, stor_connection,
+};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+// Piped rows are staged here so the comparison can run inside the engine;
+// the nu_stor_ prefix keeps the staging table out of `stor tables`.
+const PIPED_TABLE: &str = "nu_stor_diff_input";
+
+#[derive(Clone)]
+pub struct StorDiff;
+
+impl Command for StorDiff {
+    fn name(&self) -> &str {
+        "stor diff"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::Nothing, Type::Table(vec![])),
+                (Type::Table(vec![]), Type::Table(vec![])),
+            ])
+            .allow_variants_without_examples(true)
+            .required("left", SyntaxShape::String, "left table name (or query with --queries)")
+            .optional(
+                "right",
+                SyntaxShape::String,
+                "right table name (or query with --queries); piped rows when omitted",
+            )
+            .switch(
+                "queries",
+                "treat the arguments as SELECT statements instead of table names",
+                Some('q'),
+            )
+            .named(
+                "key",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "classify rows as added/removed/changed by these key columns",
+                Some('k'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Compare two tables or queries row by row."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Returns the rows that appear on only one side, tagged with a `side`
+column. With --key the rows are instead classified in a `status` column:
+added (key only on the right), removed (key only on the left), or changed
+(key on both sides with different values, showing the right-hand version).
+Piping a table instead of naming a right side compares the left table
+against the piped rows, which makes ETL output easy to validate before
+loading. Both sides must produce the same columns."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Compare a staging table against production",
+                example: "stor diff staging production",
+                result: None,
+            },
+            Example {
+                description: "Compare two query results",
+                example: r#"stor diff "SELECT id, total FROM a" "SELECT id, total FROM b" --queries"#,
+                result: None,
+            },
+            Example {
+                description: "Classify the differences by primary key",
+                example: "stor diff staging production --key [id]",
+                result: None,
+            },
+            Example {
+                description: "Compare a table against piped rows",
+                example: "open new.csv | stor diff users",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "diff", "compare", "except", "changed"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        register_ctrlc(&engine_state.ctrlc);
+        let left: String = call.req(engine_state, stack, 0)?;
+        let right: Option<String> = call.opt(engine_state, stack, 1)?;
+        let queries = call.has_flag("queries");
+        let keys: Option<Vec<String>> = call.get_flag(engine_state, stack, "key")?;
+
+        let conn = stor_connection(span)?;
+
+        let (right, piped) = match right {
+            Some(right) => (right, false),
+            None => {
+                stage_piped_rows(&conn, input, span)?;
+                (PIPED_TABLE.to_string(), true)
+            }
+        };
+
+        let (left, right) = if queries {
+            (left, right)
+        } else {
+            (
+                format!("SELECT * FROM {}", quote_ident(&left)),
+                format!("SELECT * FROM {}", quote_ident(&right)),
+            )
+        };
+
+        let sql = diff_sql(&left, &right, keys.as_deref());
+
+        let result = run_stor_query(&conn, &sql, span);
+        if piped {
+            let _ = conn.execute_batch(&format!("DROP TABLE IF EXISTS {PIPED_TABLE}"));
+        }
+        result.map(IntoPipelineData::into_pipeline_data)
+    }
+}
+
+// The comparison query over the two side queries: a symmetric EXCEPT tagged
+// with a `side` column, or with keys an added/removed/changed classification
+// in a `status` column.
+fn diff_sql(left: &str, right: &str, keys: Option<&[String]>) -> String {
+    match keys {
+        None => format!(
+            "SELECT 'left' AS side, * FROM (({left}) EXCEPT ({right})) \
+             UNION ALL \
+             SELECT 'right' AS side, * FROM (({right}) EXCEPT ({left}))"
+        ),
+        Some(keys) => {
+            let both = |a: &str, b: &str| {
+                keys.iter()
+                    .map(|key| format!("{a}.{0} = {b}.{0}", quote_ident(key)))
+                    .collect::<Vec<_>>()
+                    .join(" AND ")
+            };
+            let l_r = both("l", "r");
+            let r_l = both("r", "l");
+            let l_d = both("l", "d");
+            format!(
+                "WITH l AS ({left}), r AS ({right}) \
+                 SELECT 'added' AS status, * FROM r \
+                 WHERE NOT EXISTS (SELECT 1 FROM l WHERE {l_r}) \
+                 UNION ALL \
+                 SELECT 'removed' AS status, * FROM l \
+                 WHERE NOT EXISTS (SELECT 1 FROM r WHERE {r_l}) \
+                 UNION ALL \
+                 SELECT 'changed' AS status, * \
+                 FROM (SELECT * FROM r EXCEPT SELECT * FROM l) AS d \
+                 WHERE EXISTS (SELECT 1 FROM l WHERE {l_d})"
+            )
+        }
+    }
+}
+
+// Load the piped records into the staging table, replacing whatever a
+// previous diff left behind.
+fn stage_piped_rows(
+    conn: &duckdb::Connection,
+    input: PipelineData,
+    span: nu_protocol::Span,
+) -> Result<(), ShellError> {
+    run_stor_execute(conn, &format!("DROP TABLE IF EXISTS {PIPED_TABLE}"), span)?;
+
+    let mut columns: Option<Vec<String>> = None;
+    for value in input {
+        let value_span = value.span();
+        let Value::Record { val: record, .. } = value else {
+            return Err(ShellError::OnlySupportsThisInputType {
+                exp_input_type: "record".into(),
+                wrong_type: value.get_type().to_string(),
+                dst_span: span,
+                src_span: value_span,
+            });
+        };
+
+        if columns.is_none() {
+            columns = Some(ensure_table_for_record(conn, PIPED_TABLE, &record, span)?);
+        }
+        let columns = columns.as_ref().expect("columns were just resolved");
+        insert_record(conn, PIPED_TABLE, columns, &record, span)?;
+    }
+
+    if columns.is_none() {
+        return Err(ShellError::GenericError(
+            "Nothing to compare".into(),
+            "pipe a table in or name a right-hand table".into(),
+            Some(span),
+            None,
+            Vec::new(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorDiff {});
+    }
+
+    #[test]
+    fn diff_sql_without_keys_tags_each_side() {
+        let sql = diff_sql("SELECT * FROM \"a\"", "SELECT * FROM \"b\"", None);
+        assert_eq!(
+            sql,
+            "SELECT 'left' AS side, * \
+             FROM ((SELECT * FROM \"a\") EXCEPT (SELECT * FROM \"b\")) \
+             UNION ALL \
+             SELECT 'right' AS side, * \
+             FROM ((SELECT * FROM \"b\") EXCEPT (SELECT * FROM \"a\"))"
+        );
+    }
+
+    #[test]
+    fn diff_sql_with_keys_classifies_by_status() {
+        let keys = vec!["id".to_string(), "region".to_string()];
+        let sql = diff_sql("SELECT * FROM \"a\"", "SELECT * FROM \"b\"", Some(&keys));
+        assert!(sql.starts_with("WITH l AS (SELECT * FROM \"a\"), r AS (SELECT * FROM \"b\")"));
+        assert!(sql.contains(
+            "SELECT 'added' AS status, * FROM r \
+             WHERE NOT EXISTS (SELECT 1 FROM l WHERE l.\"id\" = r.\"id\" AND l.\"region\" = r.\"region\")"
+        ));
+        assert!(sql.contains(
+            "SELECT 'removed' AS status, * FROM l \
+             WHERE NOT EXISTS (SELECT 1 FROM r WHERE r.\"id\" = l.\"id\" AND r.\"region\" = l.\"region\")"
+        ));
+        assert!(sql.contains(
+            "SELECT 'changed' AS status, * \
+             FROM (SELECT * FROM r EXCEPT SELECT * FROM l) AS d \
+             WHERE EXISTS (SELECT 1 FROM l WHERE l.\"id\" = d.\"id\" AND l.\"region\" = d.\"region\")"
+        ));
+    }
+}
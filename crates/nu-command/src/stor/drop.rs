@@ -0,0 +1,158 @@
+use super::db::{quote_ident, run_stor_execute, stor_connection};
+use dialoguer::Input;
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct StorDrop;
+
+impl Command for StorDrop {
+    fn name(&self) -> &str {
+        "stor drop"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Bool)])
+            .allow_variants_without_examples(true)
+            .required("name", SyntaxShape::String, "table or view to drop")
+            .switch("view", "drop a view instead of a table", Some('v'))
+            .switch("if-exists", "do nothing if the object does not exist", Some('e'))
+            .switch("force", "drop without asking for confirmation", Some('f'))
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Drop a table or view, asking for confirmation first."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Returns whether the object existed. In non-interactive contexts pass
+--force to skip the confirmation prompt."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Drop a table without prompting",
+                example: "stor drop scratch --force",
+                result: None,
+            },
+            Example {
+                description: "Drop a view if it exists",
+                example: "stor drop recent_logs --view --if-exists --force",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "drop", "table", "view", "remove"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        let view = call.has_flag("view");
+        let if_exists = call.has_flag("if-exists");
+        let force = call.has_flag("force");
+
+        let kind = if view { "view" } else { "table" };
+        let conn = stor_connection(span)?;
+
+        let exists: i64 = conn
+            .query_row(
+                &format!(
+                    "SELECT count(*) FROM duckdb_{}() WHERE {}_name = ?",
+                    if view { "views" } else { "tables" },
+                    kind
+                ),
+                [&name],
+                |row| row.get(0),
+            )
+            .map_err(|e| {
+                ShellError::GenericError(
+                    format!("Failed to look up {kind} {name}"),
+                    e.to_string(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                )
+            })?;
+
+        if exists == 0 {
+            if if_exists {
+                return Ok(Value::bool(false, span).into_pipeline_data());
+            }
+            return Err(ShellError::GenericError(
+                format!("No {kind} named {name}"),
+                "nothing to drop".into(),
+                Some(span),
+                Some("pass --if-exists to ignore missing objects".into()),
+                Vec::new(),
+            ));
+        }
+
+        if !force && !confirmed(&format!("Drop {kind} {name}? (Y/N)")) {
+            return Err(ShellError::GenericError(
+                format!("Not dropping {kind} {name}"),
+                "not confirmed".into(),
+                Some(span),
+                Some("pass --force to drop without confirmation".into()),
+                Vec::new(),
+            ));
+        }
+
+        run_stor_execute(
+            &conn,
+            &format!(
+                "DROP {} {}",
+                kind.to_uppercase(),
+                quote_ident(&name)
+            ),
+            span,
+        )?;
+
+        Ok(Value::bool(true, span).into_pipeline_data())
+    }
+}
+
+// Same one-letter prompt the filesystem commands use for rm -i. Shared
+// with `stor truncate`.
+pub(super) fn confirmed(prompt: &str) -> bool {
+    Input::<String>::new()
+        .with_prompt(prompt)
+        .validate_with(|input: &String| -> Result<(), String> {
+            if matches!(input.as_str(), "y" | "Y" | "n" | "N") {
+                Ok(())
+            } else {
+                Err("Enter only one letter (Y/N)".to_string())
+            }
+        })
+        .interact()
+        .map(|answer| matches!(answer.as_str(), "y" | "Y"))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorDrop {});
+    }
+}
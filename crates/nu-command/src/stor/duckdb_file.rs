@@ -0,0 +1,258 @@
+use super::db::prepared_statement_to_nu_list;
+use duckdb::{Config, Connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, CustomValue, Example, IntoPipelineData, PipelineData, Record, ShellError, Signature,
+    Span, Spanned, SyntaxShape, Type, Value,
+};
+use serde::{Deserialize, Serialize};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+// A DuckDB file starts with an 8-byte checksum followed by this marker.
+const DUCKDB_MAGIC_BYTES: &[u8] = b"DUCK";
+const DUCKDB_MAGIC_OFFSET: usize = 8;
+
+/// A DuckDB database file as a custom value: cell-path access reads single
+/// tables, collapsing to a base value reads the whole database. Mirrors the
+/// `SQLiteDatabase` value that `open` produces for SQLite files.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DuckDBDatabase {
+    pub path: PathBuf,
+    // resource limits applied when the file is (re)opened for reading
+    pub threads: Option<i64>,
+    pub memory_limit: Option<String>,
+}
+
+impl DuckDBDatabase {
+    pub fn new(path: &Path, threads: Option<i64>, memory_limit: Option<String>) -> Self {
+        Self {
+            path: PathBuf::from(path),
+            threads,
+            memory_limit,
+        }
+    }
+
+    pub fn into_value(self, span: Span) -> Value {
+        Value::custom_value(Box::new(self), span)
+    }
+}
+
+impl CustomValue for DuckDBDatabase {
+    fn clone_value(&self, span: Span) -> Value {
+        DuckDBDatabase {
+            path: self.path.clone(),
+            threads: self.threads,
+            memory_limit: self.memory_limit.clone(),
+        }
+        .into_value(span)
+    }
+
+    fn value_string(&self) -> String {
+        self.typetag_name().to_string()
+    }
+
+    fn to_base_value(&self, span: Span) -> Result<Value, ShellError> {
+        let db = open_duckdb_file(&self.path, self.threads, self.memory_limit.as_deref(), span)?;
+
+        let mut record = Record::new();
+        for table in file_table_names(&db, span)? {
+            record.push(table.clone(), read_single_table(&db, &table, span)?);
+        }
+
+        Ok(Value::record(record, span))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn follow_path_int(&self, _count: usize, span: Span) -> Result<Value, ShellError> {
+        Err(ShellError::IncompatiblePathAccess {
+            type_name: "DuckDB databases do not support integer-indexed access. Try specifying a table name instead".into(),
+            span,
+        })
+    }
+
+    fn follow_path_string(&self, column_name: String, span: Span) -> Result<Value, ShellError> {
+        let db = open_duckdb_file(&self.path, self.threads, self.memory_limit.as_deref(), span)?;
+        read_single_table(&db, &column_name, span)
+    }
+
+    fn typetag_name(&self) -> &'static str {
+        "DuckDBDatabase"
+    }
+
+    fn typetag_deserialize(&self) {
+        unimplemented!("typetag_deserialize")
+    }
+}
+
+fn open_duckdb_file(
+    path: &Path,
+    threads: Option<i64>,
+    memory_limit: Option<&str>,
+    call_span: Span,
+) -> Result<Connection, ShellError> {
+    let open_error = |e: duckdb::Error| {
+        ShellError::GenericError(
+            "Failed to open DuckDB database".into(),
+            e.to_string(),
+            Some(call_span),
+            None,
+            Vec::new(),
+        )
+    };
+
+    let mut config = Config::default();
+    if let Some(threads) = threads {
+        config = config.threads(threads).map_err(open_error)?;
+    }
+    if let Some(memory_limit) = memory_limit {
+        config = config.max_memory(memory_limit).map_err(open_error)?;
+    }
+
+    Connection::open_with_flags(path, config).map_err(open_error)
+}
+
+// Tables and views alike: saved views surface through the cell path the same
+// way tables do, e.g. `$db.my_view`.
+fn file_table_names(db: &Connection, call_span: Span) -> Result<Vec<String>, ShellError> {
+    db.prepare(
+        "SELECT table_name FROM duckdb_tables()
+         UNION ALL
+         SELECT view_name FROM duckdb_views() WHERE NOT internal
+         ORDER BY 1",
+    )
+        .and_then(|mut stmt| {
+            stmt.query_map([], |row| row.get(0))
+                .and_then(|rows| rows.collect())
+        })
+        .map_err(|e| {
+            ShellError::GenericError(
+                "Failed to list tables in DuckDB database".into(),
+                e.to_string(),
+                Some(call_span),
+                None,
+                Vec::new(),
+            )
+        })
+}
+
+fn read_single_table(db: &Connection, table: &str, call_span: Span) -> Result<Value, ShellError> {
+    db.prepare(&format!(
+        "SELECT * FROM {}",
+        super::db::quote_ident(table)
+    ))
+    .and_then(|stmt| prepared_statement_to_nu_list(stmt, call_span))
+    .map_err(|e| {
+        ShellError::GenericError(
+            "Failed to read from DuckDB database".into(),
+            e.to_string(),
+            Some(call_span),
+            None,
+            Vec::new(),
+        )
+    })
+}
+
+fn is_duckdb_file(path: &Path) -> bool {
+    let Ok(mut file) = std::fs::File::open(path) else {
+        return false;
+    };
+    let mut header = [0u8; DUCKDB_MAGIC_OFFSET + DUCKDB_MAGIC_BYTES.len()];
+    file.read_exact(&mut header).is_ok() && header[DUCKDB_MAGIC_OFFSET..] == *DUCKDB_MAGIC_BYTES
+}
+
+#[derive(Clone)]
+pub struct StorOpen;
+
+impl Command for StorOpen {
+    fn name(&self) -> &str {
+        "stor open"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Any)])
+            .allow_variants_without_examples(true)
+            .required("path", SyntaxShape::Filepath, "DuckDB database file to open")
+            .named(
+                "threads",
+                SyntaxShape::Int,
+                "maximum number of threads DuckDB may use",
+                Some('t'),
+            )
+            .named(
+                "memory-limit",
+                SyntaxShape::String,
+                "maximum memory DuckDB may use, e.g. 4GB",
+                Some('m'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Open an existing DuckDB file as a database value."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The returned value reads lazily: `get <table>` loads just that table,
+collapsing the value loads everything. The file is validated to be a DuckDB
+database before anything is read."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Open a database and filter one of its tables",
+            example: "stor open sales.duckdb | get orders | where total > 100",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "open", "file", "load"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let path: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let threads: Option<i64> = call.get_flag(engine_state, stack, "threads")?;
+        let memory_limit: Option<String> = call.get_flag(engine_state, stack, "memory-limit")?;
+        let path = nu_path::expand_path_with(&path.item, std::env::current_dir()?);
+
+        if !is_duckdb_file(&path) {
+            return Err(ShellError::GenericError(
+                "Not a DuckDB database".into(),
+                format!("{} has no DuckDB file header", path.to_string_lossy()),
+                Some(span),
+                None,
+                Vec::new(),
+            ));
+        }
+
+        Ok(DuckDBDatabase::new(&path, threads, memory_limit)
+            .into_value(span)
+            .into_pipeline_data())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorOpen {});
+    }
+}
@@ -0,0 +1,230 @@
+use super::db::{run_stor_execute, run_stor_query, split_sql_statements, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Spanned, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct StorDumpSchema;
+
+impl Command for StorDumpSchema {
+    fn name(&self) -> &str {
+        "stor dump-schema"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::Nothing, Type::Table(vec![])),
+                (Type::Nothing, Type::String),
+            ])
+            .allow_variants_without_examples(true)
+            .switch(
+                "script",
+                "emit one runnable SQL script instead of a table",
+                Some('s'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Emit the DDL for everything defined in the in-memory database."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Collects the CREATE statements for tables, views, indexes, and
+sequences from the catalog. The default table form is easy to filter;
+--script produces text ready for `save schema.sql`, versioning in git, and
+rebuilding an empty database with `stor restore`."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Inspect the DDL per object",
+                example: "stor dump-schema",
+                result: None,
+            },
+            Example {
+                description: "Version the schema in git",
+                example: "stor dump-schema --script | save schema.sql",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "schema", "ddl", "dump", "version"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let conn = stor_connection(span)?;
+
+        let dump = run_stor_query(
+            &conn,
+            "SELECT 'table' AS kind, table_name AS name, sql FROM duckdb_tables() \
+             WHERE NOT internal AND table_name NOT LIKE 'nu_stor_%' \
+             UNION ALL \
+             SELECT 'view', view_name, sql FROM duckdb_views() WHERE NOT internal \
+             UNION ALL \
+             SELECT 'index', index_name, sql FROM duckdb_indexes() \
+             UNION ALL \
+             SELECT 'sequence', sequence_name, sql FROM duckdb_sequences() \
+             ORDER BY kind, name",
+            span,
+        )?;
+
+        if !call.has_flag("script") {
+            return Ok(dump.into_pipeline_data());
+        }
+
+        let mut script = String::new();
+        if let Value::List { vals, .. } = &dump {
+            for row in vals {
+                let Value::Record { val: record, .. } = row else {
+                    continue;
+                };
+                if let Some((_, sql)) = record.iter().find(|(col, _)| *col == "sql") {
+                    if let Ok(sql) = sql.as_string() {
+                        if !sql.is_empty() {
+                            script.push_str(sql.trim_end_matches(';'));
+                            script.push_str(";\n");
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(Value::string(script, span).into_pipeline_data())
+    }
+}
+
+#[derive(Clone)]
+pub struct StorRestore;
+
+impl Command for StorRestore {
+    fn name(&self) -> &str {
+        "stor restore"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required(
+                "file",
+                SyntaxShape::Filepath,
+                "SQL script to apply, typically from `stor dump-schema --script`",
+            )
+            .named(
+                "to",
+                SyntaxShape::Filepath,
+                "apply the script inside this DuckDB file instead of in memory",
+                None,
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Apply a schema dump, rebuilding its objects."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Runs the statements of the script in order. With --to the target
+file is attached and made the default catalog for the duration, so an
+empty database file can be rebuilt from a dump without touching the
+in-memory store."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Rebuild the schema in the current session",
+                example: "stor restore schema.sql",
+                result: None,
+            },
+            Example {
+                description: "Build an empty database file from a dump",
+                example: "stor restore schema.sql --to fresh.duckdb",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "restore", "schema", "rebuild", "apply"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let file: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let to: Option<Spanned<String>> = call.get_flag(engine_state, stack, "to")?;
+
+        let script = std::fs::read_to_string(&file.item).map_err(|e| {
+            ShellError::GenericError(
+                format!("Failed to read {}", file.item),
+                e.to_string(),
+                Some(file.span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+        let conn = stor_connection(span)?;
+        let attached = match &to {
+            Some(to) => {
+                let path = nu_path::expand_path_with(&to.item, std::env::current_dir()?);
+                let path = path.to_string_lossy().replace('\'', "''");
+                run_stor_execute(&conn, &format!("ATTACH '{path}' AS __stor_restore"), span)?;
+                run_stor_execute(&conn, "USE __stor_restore", span)?;
+                true
+            }
+            None => false,
+        };
+
+        let mut result = Ok(());
+        for statement in split_sql_statements(&script) {
+            if let Err(err) = run_stor_execute(&conn, &statement, span) {
+                result = Err(err);
+                break;
+            }
+        }
+
+        if attached {
+            // Switch back even when a statement failed so the store stays usable.
+            let _ = conn.execute_batch("USE memory; DETACH __stor_restore;");
+        }
+        result?;
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorDumpSchema {});
+        test_examples(StorRestore {});
+    }
+}
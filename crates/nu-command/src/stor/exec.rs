@@ -0,0 +1,87 @@
+use super::db::{register_ctrlc, run_stor_execute, split_sql_statements, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    record, Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct StorExec;
+
+impl Command for StorExec {
+    fn name(&self) -> &str {
+        "stor exec"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Record(vec![]))])
+            .allow_variants_without_examples(true)
+            .required(
+                "statements",
+                SyntaxShape::String,
+                "one or more ;-separated SQL statements to execute",
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Run DDL/DML statements, returning the number of affected rows."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The counterpart to `stor query` for statements that don't return rows
+(CREATE, INSERT, UPDATE, ...). Multiple statements separated by ; run in
+order; the returned rows_affected sums their individual counts."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Create a table and fill it in one go",
+            example: r#"stor exec "create table t (x int); insert into t values (1), (2)""#,
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "exec", "execute", "ddl", "update"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let statements: String = call.req(engine_state, stack, 0)?;
+        register_ctrlc(&engine_state.ctrlc);
+
+        let conn = stor_connection(span)?;
+        let mut rows_affected: i64 = 0;
+        for statement in split_sql_statements(&statements) {
+            rows_affected += run_stor_execute(&conn, &statement, span)? as i64;
+        }
+
+        Ok(Value::record(
+            record! { "rows_affected" => Value::int(rows_affected, span) },
+            span,
+        )
+        .into_pipeline_data())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorExec {});
+    }
+}
@@ -0,0 +1,179 @@
+use super::db::{quote_ident, run_stor_execute, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Spanned,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct StorExport;
+
+impl Command for StorExport {
+    fn name(&self) -> &str {
+        "stor export"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+            .allow_variants_without_examples(true)
+            .required(
+                "source",
+                SyntaxShape::String,
+                "table name or SELECT query to export",
+            )
+            .required_named(
+                "to",
+                SyntaxShape::Filepath,
+                "output file; the extension picks the format",
+                Some('t'),
+            )
+            .named(
+                "format",
+                SyntaxShape::String,
+                "parquet, csv or json, overriding the extension",
+                Some('f'),
+            )
+            .named(
+                "compression",
+                SyntaxShape::String,
+                "compression codec, e.g. zstd or gzip",
+                Some('c'),
+            )
+            .named(
+                "delimiter",
+                SyntaxShape::String,
+                "field delimiter for CSV output",
+                Some('d'),
+            )
+            .named(
+                "partition-by",
+                SyntaxShape::String,
+                "write a directory partitioned by this column",
+                Some('p'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Export a table or query result to Parquet, CSV, or JSON."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Wraps DuckDB's `COPY ... TO`. The format is inferred from the output
+file's extension (.parquet, .csv, .json/.ndjson) unless --format is given.
+Returns the written path."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Export a table to Parquet",
+                example: "stor export logs --to logs.parquet",
+                result: None,
+            },
+            Example {
+                description: "Export a query to compressed CSV",
+                example: r#"stor export "select * from logs where level = 'error'" --to errors.csv --compression gzip"#,
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "export", "copy", "parquet", "csv", "json"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let source: String = call.req(engine_state, stack, 0)?;
+        let to: Spanned<String> = call
+            .get_flag(engine_state, stack, "to")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "to".into(),
+                span,
+            })?;
+        let format: Option<String> = call.get_flag(engine_state, stack, "format")?;
+        let compression: Option<String> = call.get_flag(engine_state, stack, "compression")?;
+        let delimiter: Option<String> = call.get_flag(engine_state, stack, "delimiter")?;
+        let partition_by: Option<String> = call.get_flag(engine_state, stack, "partition-by")?;
+
+        let format = match format {
+            Some(format) => format.to_lowercase(),
+            None => match to.item.rsplit('.').next() {
+                Some("parquet") => "parquet".into(),
+                Some("csv") => "csv".into(),
+                Some("json") | Some("ndjson") | Some("jsonl") => "json".into(),
+                _ => {
+                    return Err(ShellError::GenericError(
+                        "Cannot infer the output format".into(),
+                        format!("unrecognized extension on {}", to.item),
+                        Some(to.span),
+                        Some("use a .parquet/.csv/.json path or pass --format".into()),
+                        Vec::new(),
+                    ))
+                }
+            },
+        };
+
+        let mut options = vec![format!("FORMAT {}", format.to_uppercase())];
+        if let Some(compression) = compression {
+            options.push(format!("COMPRESSION '{}'", compression.replace('\'', "''")));
+        }
+        if let Some(delimiter) = delimiter {
+            options.push(format!("DELIMITER '{}'", delimiter.replace('\'', "''")));
+        }
+        if let Some(partition_by) = partition_by {
+            options.push(format!("PARTITION_BY ({})", quote_ident(&partition_by)));
+        }
+
+        // a bare identifier is a table; anything else is treated as a query.
+        // Qualified names quote each dot-separated part on its own, so
+        // main.orders resolves as table orders in schema main rather than as
+        // one identifier with a dot in it
+        let trimmed = source.trim();
+        let source_sql = if trimmed
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+        {
+            trimmed
+                .split('.')
+                .map(quote_ident)
+                .collect::<Vec<_>>()
+                .join(".")
+        } else {
+            format!("({trimmed})")
+        };
+
+        let sql = format!(
+            "COPY {source_sql} TO '{}' ({})",
+            to.item.replace('\'', "''"),
+            options.join(", ")
+        );
+
+        let conn = stor_connection(span)?;
+        run_stor_execute(&conn, &sql, span)?;
+
+        Ok(Value::string(to.item, span).into_pipeline_data())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorExport {});
+    }
+}
@@ -0,0 +1,201 @@
+use super::db::{run_stor_execute, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct StorExportDb;
+
+impl Command for StorExportDb {
+    fn name(&self) -> &str {
+        "stor export-db"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required(
+                "directory",
+                SyntaxShape::Filepath,
+                "directory the database is serialized into",
+            )
+            .named(
+                "format",
+                SyntaxShape::String,
+                "data file format, csv or parquet (default csv)",
+                Some('f'),
+            )
+            .named(
+                "compression",
+                SyntaxShape::String,
+                "compression codec for the data files, e.g. gzip or zstd",
+                Some('c'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Serialize the whole in-memory database to a directory."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Wraps DuckDB's EXPORT DATABASE: the directory receives the schema as
+SQL plus one data file per table, ready to rebuild elsewhere with
+`stor import-db`."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Export everything as CSV",
+                example: "stor export-db backup/",
+                result: None,
+            },
+            Example {
+                description: "Export as compressed parquet",
+                example: "stor export-db backup/ --format parquet --compression zstd",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "export", "backup", "serialize"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let directory: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let format: Option<String> = call.get_flag(engine_state, stack, "format")?;
+        let compression: Option<String> = call.get_flag(engine_state, stack, "compression")?;
+
+        let mut options = Vec::new();
+        if let Some(format) = format {
+            options.push(format!("FORMAT {}", validated_word(&format, span)?));
+        }
+        if let Some(compression) = compression {
+            options.push(format!("COMPRESSION {}", validated_word(&compression, span)?));
+        }
+
+        let path = nu_path::expand_path_with(&directory.item, std::env::current_dir()?);
+        let mut sql = format!(
+            "EXPORT DATABASE '{}'",
+            path.to_string_lossy().replace('\'', "''")
+        );
+        if !options.is_empty() {
+            sql.push_str(&format!(" ({})", options.join(", ")));
+        }
+
+        let conn = stor_connection(span)?;
+        run_stor_execute(&conn, &sql, span)?;
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[derive(Clone)]
+pub struct StorImportDb;
+
+impl Command for StorImportDb {
+    fn name(&self) -> &str {
+        "stor import-db"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required(
+                "directory",
+                SyntaxShape::Filepath,
+                "directory produced by `stor export-db`",
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Rebuild the in-memory database from an exported directory."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Wraps DuckDB's IMPORT DATABASE, replaying the exported schema and
+loading the data files. Existing objects with the same names cause the
+import to fail, so it's best run against a fresh session or after
+`stor reset`."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Load a previously exported database",
+            example: "stor import-db backup/",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "import", "restore", "load"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let directory: Spanned<String> = call.req(engine_state, stack, 0)?;
+
+        let path = nu_path::expand_path_with(&directory.item, std::env::current_dir()?);
+        let conn = stor_connection(span)?;
+        run_stor_execute(
+            &conn,
+            &format!(
+                "IMPORT DATABASE '{}'",
+                path.to_string_lossy().replace('\'', "''")
+            ),
+            span,
+        )?;
+
+        Ok(PipelineData::empty())
+    }
+}
+
+// EXPORT options are keywords, not strings; accept only bare identifiers so
+// nothing can smuggle extra SQL into the statement.
+fn validated_word(word: &str, span: nu_protocol::Span) -> Result<String, ShellError> {
+    if !word.is_empty() && word.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        Ok(word.to_uppercase())
+    } else {
+        Err(ShellError::GenericError(
+            format!("Invalid option value {word}"),
+            "expected a bare word like csv, parquet, gzip, or zstd".into(),
+            Some(span),
+            None,
+            Vec::new(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorExportDb {});
+        test_examples(StorImportDb {});
+    }
+}
@@ -0,0 +1,201 @@
+use super::db::{run_stor_execute, run_stor_query, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type,
+};
+
+// DuckDB has no placeholder support in INSTALL/LOAD, so keep the interpolated
+// name down to what an extension can actually be called.
+fn validated_extension_name(name: &str, span: Span) -> Result<&str, ShellError> {
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        Ok(name)
+    } else {
+        Err(ShellError::GenericError(
+            format!("Invalid extension name {name}"),
+            "extension names are alphanumeric with underscores".into(),
+            Some(span),
+            None,
+            Vec::new(),
+        ))
+    }
+}
+
+#[derive(Clone)]
+pub struct StorExtensionInstall;
+
+impl Command for StorExtensionInstall {
+    fn name(&self) -> &str {
+        "stor extension install"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required(
+                "name",
+                SyntaxShape::String,
+                "name of the DuckDB extension to install",
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Download and install a DuckDB extension."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Install the httpfs extension",
+            example: "stor extension install httpfs",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "extension", "install", "httpfs", "spatial"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        let name = validated_extension_name(&name, span)?;
+
+        let conn = stor_connection(span)?;
+        run_stor_execute(&conn, &format!("INSTALL {name}"), span)?;
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[derive(Clone)]
+pub struct StorExtensionLoad;
+
+impl Command for StorExtensionLoad {
+    fn name(&self) -> &str {
+        "stor extension load"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required(
+                "name",
+                SyntaxShape::String,
+                "name of the installed extension to load",
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Load an installed DuckDB extension into the session."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Load the json extension",
+            example: "stor extension load json",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "extension", "load"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        let name = validated_extension_name(&name, span)?;
+
+        let conn = stor_connection(span)?;
+        run_stor_execute(&conn, &format!("LOAD {name}"), span)?;
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[derive(Clone)]
+pub struct StorExtensionList;
+
+impl Command for StorExtensionList {
+    fn name(&self) -> &str {
+        "stor extension list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "List DuckDB extensions and their installed/loaded state."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "See which extensions are loaded",
+            example: "stor extension list | where loaded",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "extension", "list", "installed", "loaded"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let conn = stor_connection(span)?;
+
+        run_stor_query(
+            &conn,
+            "SELECT extension_name AS name, installed, loaded, description
+             FROM duckdb_extensions() ORDER BY extension_name",
+            span,
+        )
+        .map(IntoPipelineData::into_pipeline_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorExtensionInstall {});
+        test_examples(StorExtensionLoad {});
+        test_examples(StorExtensionList {});
+    }
+}
@@ -0,0 +1,57 @@
+use super::db::{quote_ident, record_startup_sql, stor_connection};
+use nu_protocol::{ShellError, Span};
+
+/// A scalar SQL function contributed by an embedder or plugin.
+///
+/// DuckDB cannot yet register native Rust callbacks through the bindings we
+/// use, so contributed functions are expressed as SQL macro bodies. They are
+/// created on the shared in-memory connection right away and replayed on any
+/// fresh connection, so they behave like built-ins for the rest of the
+/// session.
+#[derive(Debug, Clone)]
+pub struct StorScalarFunction {
+    /// Name the function is callable under in SQL.
+    pub name: String,
+    /// Parameter names, in order.
+    pub params: Vec<String>,
+    /// SQL expression forming the function body.
+    pub body: String,
+}
+
+/// Register a scalar function with the shared stor database.
+///
+/// This is the extension point plugins use to make their helpers queryable;
+/// the `stor` commands themselves go through it too, so contributed functions
+/// and built-ins are listed and replayed the same way.
+pub fn register_scalar_function(
+    function: &StorScalarFunction,
+    call_span: Span,
+) -> Result<(), ShellError> {
+    let param_list = function
+        .params
+        .iter()
+        .map(|p| quote_ident(p))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let sql = format!(
+        "CREATE OR REPLACE MACRO {}({}) AS {}",
+        quote_ident(&function.name),
+        param_list,
+        function.body
+    );
+
+    let conn = stor_connection(call_span)?;
+    conn.execute_batch(&sql).map_err(|e| {
+        ShellError::GenericError(
+            format!("Failed to register scalar function {}", function.name),
+            e.to_string(),
+            Some(call_span),
+            None,
+            Vec::new(),
+        )
+    })?;
+    record_startup_sql(&function.name, &sql);
+
+    Ok(())
+}
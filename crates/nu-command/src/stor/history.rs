@@ -0,0 +1,152 @@
+use chrono::{DateTime, FixedOffset, Local};
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    record, Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+};
+use once_cell::sync::Lazy;
+use std::sync::Mutex;
+
+// Session log of every statement the stor helpers ran, captured at the same
+// points that fire the completion hooks. Kept in nu process memory rather
+// than in a stor table so the log survives `stor reset` and never shows up
+// in the user's own schema.
+static HISTORY: Lazy<Mutex<Vec<HistoryEntry>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+struct HistoryEntry {
+    at: DateTime<FixedOffset>,
+    sql: String,
+    duration: std::time::Duration,
+    rows: Option<i64>,
+    success: bool,
+}
+
+/// Append a finished statement to the session history.
+pub(super) fn record_statement(
+    sql: &str,
+    elapsed: std::time::Duration,
+    rows: Option<i64>,
+    success: bool,
+) {
+    if let Ok(mut history) = HISTORY.lock() {
+        history.push(HistoryEntry {
+            at: Local::now().fixed_offset(),
+            sql: sql.to_string(),
+            duration: elapsed,
+            rows,
+            success,
+        });
+    }
+}
+
+#[derive(Clone)]
+pub struct StorHistory;
+
+impl Command for StorHistory {
+    fn name(&self) -> &str {
+        "stor history"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::Nothing, Type::Table(vec![])),
+                (Type::Nothing, Type::Nothing),
+            ])
+            .allow_variants_without_examples(true)
+            .switch("clear", "forget the recorded statements", Some('c'))
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Show the statements executed against the in-memory database."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Every statement run through the stor commands is logged with its
+start time, duration, row count, and outcome, so an ad-hoc analysis session
+can be reconstructed into a script afterwards."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Review the session so far",
+                example: "stor history",
+                result: None,
+            },
+            Example {
+                description: "Keep only the successful statements as a script",
+                example: "stor history | where success | get sql | save session.sql",
+                result: None,
+            },
+            Example {
+                description: "Start over",
+                example: "stor history --clear",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "history", "log", "statements", "audit"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let mut history = HISTORY.lock().map_err(|_| {
+            ShellError::GenericError(
+                "stor history is poisoned".into(),
+                "another thread panicked while logging a statement".into(),
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+        if call.has_flag("clear") {
+            history.clear();
+            return Ok(PipelineData::empty());
+        }
+
+        let rows = history
+            .iter()
+            .map(|entry| {
+                Value::record(
+                    record! {
+                        "at" => Value::date(entry.at, span),
+                        "sql" => Value::string(entry.sql.clone(), span),
+                        "duration" => Value::duration(entry.duration.as_nanos() as i64, span),
+                        "rows" => match entry.rows {
+                            Some(rows) => Value::int(rows, span),
+                            None => Value::nothing(span),
+                        },
+                        "success" => Value::bool(entry.success, span),
+                    },
+                    span,
+                )
+            })
+            .collect();
+
+        Ok(Value::list(rows, span).into_pipeline_data())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorHistory {});
+    }
+}
@@ -0,0 +1,205 @@
+use nu_engine::{eval_block_with_early_return, CallExt};
+use nu_protocol::{
+    ast::Call,
+    engine::{Closure, Command, EngineState, Stack},
+    record, Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+use once_cell::sync::Lazy;
+use std::sync::Mutex;
+
+// Closures run after every stor statement finishes. Hooks run on their own
+// thread so a hook is free to call stor commands itself without deadlocking
+// on the shared connection.
+static COMPLETION_HOOKS: Lazy<Mutex<Vec<CompletionHook>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+struct CompletionHook {
+    engine_state: EngineState,
+    stack: Stack,
+    closure: Closure,
+}
+
+/// Notify the registered completion hooks that a statement finished. Each
+/// hook receives a record with the SQL text, the elapsed duration, and
+/// whether the statement succeeded.
+pub(super) fn notify_query_finished(sql: &str, elapsed: std::time::Duration, success: bool) {
+    let hooks = match COMPLETION_HOOKS.lock() {
+        Ok(hooks) if !hooks.is_empty() => hooks,
+        _ => return,
+    };
+
+    let span = Span::unknown();
+    for hook in hooks.iter() {
+        let engine_state = hook.engine_state.clone();
+        let mut stack = hook.stack.clone();
+        let block_id = hook.closure.block_id;
+        let event = Value::record(
+            record! {
+                "sql" => Value::string(sql, span),
+                "duration" => Value::duration(elapsed.as_nanos() as i64, span),
+                "success" => Value::bool(success, span),
+            },
+            span,
+        );
+
+        std::thread::spawn(move || {
+            let block = engine_state.get_block(block_id);
+            if let Some(var) = block.signature.get_positional(0) {
+                if let Some(var_id) = &var.var_id {
+                    stack.add_var(*var_id, event.clone());
+                }
+            }
+            let _ = eval_block_with_early_return(
+                &engine_state,
+                &mut stack,
+                block,
+                event.into_pipeline_data(),
+                true,
+                true,
+            );
+        });
+    }
+}
+
+#[derive(Clone)]
+pub struct StorHookAdd;
+
+impl Command for StorHookAdd {
+    fn name(&self) -> &str {
+        "stor hook add"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required(
+                "closure",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Record(vec![])])),
+                "closure run whenever a stor statement finishes",
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Run a closure every time a stor statement finishes."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The closure receives a record with the fields sql, duration, and success."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Log slow statements to a file",
+            example: r#"stor hook add {|event| if $event.duration > 1sec { $event | to nuon | save --append slow.log } }"#,
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "hook", "callback", "notify"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let closure: Closure = call.req(engine_state, stack, 0)?;
+
+        let hook = CompletionHook {
+            engine_state: engine_state.clone(),
+            stack: stack.captures_to_stack(closure.captures.clone()),
+            closure,
+        };
+
+        COMPLETION_HOOKS
+            .lock()
+            .map_err(|e| {
+                ShellError::GenericError(
+                    "Failed to lock the hook registry".into(),
+                    e.to_string(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                )
+            })?
+            .push(hook);
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[derive(Clone)]
+pub struct StorHookClear;
+
+impl Command for StorHookClear {
+    fn name(&self) -> &str {
+        "stor hook clear"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Remove all stor completion hooks."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Remove every registered hook",
+            example: "stor hook clear",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "hook"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        COMPLETION_HOOKS
+            .lock()
+            .map_err(|e| {
+                ShellError::GenericError(
+                    "Failed to lock the hook registry".into(),
+                    e.to_string(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                )
+            })?
+            .clear();
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorHookAdd {});
+        test_examples(StorHookClear {});
+    }
+}
@@ -0,0 +1,158 @@
+use super::db::{quote_ident, run_stor_execute, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Spanned,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct StorImport;
+
+impl Command for StorImport {
+    fn name(&self) -> &str {
+        "stor import"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Int)])
+            .allow_variants_without_examples(true)
+            .required(
+                "path",
+                SyntaxShape::String,
+                "file or glob to read; the extension picks the reader",
+            )
+            .required_named(
+                "table",
+                SyntaxShape::String,
+                "table to load the data into",
+                Some('t'),
+            )
+            .named(
+                "format",
+                SyntaxShape::String,
+                "parquet, csv or json, overriding the extension",
+                Some('f'),
+            )
+            .switch(
+                "append",
+                "append to an existing table instead of creating it",
+                Some('a'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Import CSV, Parquet, or JSON files into a table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Wraps DuckDB's read_parquet/read_csv_auto/read_json_auto, so globs
+like `data/*.parquet` work and CSV/JSON schemas are auto-detected. By default
+the table is created from the data; --append inserts into an existing table.
+Returns the number of imported rows."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Load a directory of Parquet files",
+                example: "stor import 'data/*.parquet' --table events",
+                result: None,
+            },
+            Example {
+                description: "Append a CSV file to an existing table",
+                example: "stor import new_rows.csv --table events --append",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "import", "load", "parquet", "csv", "json"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let path: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let table: String = call
+            .get_flag(engine_state, stack, "table")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "table".into(),
+                span,
+            })?;
+        let format: Option<String> = call.get_flag(engine_state, stack, "format")?;
+        let append = call.has_flag("append");
+
+        let format = match format {
+            Some(format) => format.to_lowercase(),
+            None => match path.item.rsplit('.').next() {
+                Some("parquet") => "parquet".into(),
+                Some("csv") | Some("tsv") => "csv".into(),
+                Some("json") | Some("ndjson") | Some("jsonl") => "json".into(),
+                _ => {
+                    return Err(ShellError::GenericError(
+                        "Cannot infer the input format".into(),
+                        format!("unrecognized extension on {}", path.item),
+                        Some(path.span),
+                        Some("use a .parquet/.csv/.json path or pass --format".into()),
+                        Vec::new(),
+                    ))
+                }
+            },
+        };
+
+        let reader = match format.as_str() {
+            "parquet" => "read_parquet",
+            "csv" => "read_csv_auto",
+            "json" => "read_json_auto",
+            other => {
+                return Err(ShellError::GenericError(
+                    format!("Unsupported format {other}"),
+                    "expected parquet, csv or json".into(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                ))
+            }
+        };
+
+        let source = format!("{reader}('{}')", path.item.replace('\'', "''"));
+        let sql = if append {
+            format!(
+                "INSERT INTO {} SELECT * FROM {source}",
+                quote_ident(&table)
+            )
+        } else {
+            format!(
+                "CREATE TABLE {} AS SELECT * FROM {source}",
+                quote_ident(&table)
+            )
+        };
+
+        let conn = stor_connection(span)?;
+        let imported = run_stor_execute(&conn, &sql, span)?;
+
+        Ok(Value::int(imported as i64, span).into_pipeline_data())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorImport {});
+    }
+}
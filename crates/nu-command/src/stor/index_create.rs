@@ -0,0 +1,115 @@
+use super::db::{quote_ident, run_stor_execute, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct StorIndexCreate;
+
+impl Command for StorIndexCreate {
+    fn name(&self) -> &str {
+        "stor index create"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("name", SyntaxShape::String, "name of the index to create")
+            .required_named(
+                "table-name",
+                SyntaxShape::String,
+                "name of the table to index",
+                Some('t'),
+            )
+            .required_named(
+                "columns",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "columns to index, in order",
+                Some('c'),
+            )
+            .switch("unique", "create a UNIQUE index", Some('u'))
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Create an index on a table in the in-memory database."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Create an index on the foo column of table nudb",
+                example: "stor index create idx_foo --table-name nudb --columns [foo]",
+                result: None,
+            },
+            Example {
+                description: "Create a unique, multi-column index",
+                example: "stor index create idx_bar --table-name nudb --columns [foo bar] --unique",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "index", "unique"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        let table_name: String = call
+            .get_flag(engine_state, stack, "table-name")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "table-name".into(),
+                span,
+            })?;
+        let columns: Vec<String> = call
+            .get_flag(engine_state, stack, "columns")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "columns".into(),
+                span,
+            })?;
+        let unique = call.has_flag("unique");
+
+        let column_list = columns
+            .iter()
+            .map(|c| quote_ident(c))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let sql = format!(
+            "CREATE {}INDEX {} ON {} ({})",
+            if unique { "UNIQUE " } else { "" },
+            quote_ident(&name),
+            quote_ident(&table_name),
+            column_list
+        );
+
+        let conn = stor_connection(span)?;
+        run_stor_execute(&conn, &sql, span)?;
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorIndexCreate {});
+    }
+}
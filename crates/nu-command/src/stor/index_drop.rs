@@ -0,0 +1,76 @@
+use super::db::{quote_ident, run_stor_execute, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct StorIndexDrop;
+
+impl Command for StorIndexDrop {
+    fn name(&self) -> &str {
+        "stor index drop"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("name", SyntaxShape::String, "name of the index to drop")
+            .switch("if-exists", "do not error if the index does not exist", Some('i'))
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Drop an index from the in-memory database."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Drop the index idx_foo",
+            example: "stor index drop idx_foo",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "index"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        let if_exists = call.has_flag("if-exists");
+
+        let sql = format!(
+            "DROP INDEX {}{}",
+            if if_exists { "IF EXISTS " } else { "" },
+            quote_ident(&name)
+        );
+
+        let conn = stor_connection(span)?;
+        run_stor_execute(&conn, &sql, span)?;
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorIndexDrop {});
+    }
+}
@@ -0,0 +1,68 @@
+use super::db::{run_stor_query, stor_connection};
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type,
+};
+
+#[derive(Clone)]
+pub struct StorIndexList;
+
+impl Command for StorIndexList {
+    fn name(&self) -> &str {
+        "stor index list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "List the indexes defined in the in-memory database, with their definitions."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "List all indexes",
+            example: "stor index list",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "index"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let conn = stor_connection(span)?;
+
+        run_stor_query(
+            &conn,
+            "SELECT index_name, table_name, is_unique, sql FROM duckdb_indexes()",
+            span,
+        )
+        .map(IntoPipelineData::into_pipeline_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorIndexList {});
+    }
+}
@@ -0,0 +1,245 @@
+use super::db::{
+    ensure_table_for_record, insert_record, nu_value_to_duckdb_type, quote_ident,
+    run_stor_execute, stor_connection, table_columns,
+};
+use super::progress::StorProgress;
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, Record, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct StorIngest;
+
+impl Command for StorIngest {
+    fn name(&self) -> &str {
+        "stor ingest"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::String, Type::Int),
+                (Type::List(Box::new(Type::String)), Type::Int),
+            ])
+            .allow_variants_without_examples(true)
+            .required_named(
+                "table-name",
+                SyntaxShape::String,
+                "table the lines are appended to",
+                Some('t'),
+            )
+            .switch(
+                "raw",
+                "store raw lines instead of parsing them as JSON objects",
+                Some('r'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Stream lines from an external command into a table as they arrive."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Each line becomes one row. Lines are parsed as JSON objects whose keys map
+to columns; with --raw they land unparsed in a single `line` column. Lines
+need not all have the same keys: a key the table has not seen yet adds a
+column on the fly, and a key a line omits stores NULL. Returns the number
+of ingested rows."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Ingest JSON lines produced by an external tool",
+                example: "journalctl -o json -n 100 | stor ingest --table-name logs",
+                result: None,
+            },
+            Example {
+                description: "Ingest raw text lines",
+                example: "open --raw access.log | stor ingest --table-name access --raw",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "ingest", "stream", "lines", "tail"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let table_name: String = call
+            .get_flag(engine_state, stack, "table-name")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "table-name".into(),
+                span,
+            })?;
+        let raw = call.has_flag("raw");
+        let ctrlc = engine_state.ctrlc.clone();
+
+        let progress = StorProgress::new(None, &format!("ingesting into {table_name}"));
+        let mut ingested: i64 = 0;
+        let mut carry = String::new();
+        // the table's columns, resolved from the catalog on the first line and
+        // grown in place as later lines bring new keys
+        let mut columns: Option<Vec<String>> = None;
+
+        for value in input {
+            if nu_utils::ctrl_c::was_pressed(&ctrlc) {
+                break;
+            }
+
+            let chunk = value.as_string()?;
+            carry.push_str(&chunk);
+
+            // everything up to the last newline is complete; the rest carries over
+            while let Some(newline) = carry.find('\n') {
+                let line: String = carry.drain(..=newline).collect();
+                let line = line.trim_end_matches(['\n', '\r']);
+                if !line.is_empty() {
+                    ingest_line(&table_name, line, raw, &mut columns, span)?;
+                    ingested += 1;
+                    progress.inc();
+                }
+            }
+        }
+
+        let leftover = carry.trim_end_matches(['\n', '\r']).to_string();
+        if !leftover.is_empty() {
+            ingest_line(&table_name, &leftover, raw, &mut columns, span)?;
+            ingested += 1;
+            progress.inc();
+        }
+        progress.finish();
+
+        Ok(Value::int(ingested, span).into_pipeline_data())
+    }
+}
+
+fn ingest_line(
+    table_name: &str,
+    line: &str,
+    raw: bool,
+    columns: &mut Option<Vec<String>>,
+    span: Span,
+) -> Result<(), ShellError> {
+    let conn = stor_connection(span)?;
+
+    if raw {
+        run_stor_execute(
+            &conn,
+            &format!(
+                "CREATE TABLE IF NOT EXISTS {} (line VARCHAR)",
+                quote_ident(table_name)
+            ),
+            span,
+        )?;
+        run_stor_execute(
+            &conn,
+            &format!(
+                "INSERT INTO {} VALUES ('{}')",
+                quote_ident(table_name),
+                line.replace('\'', "''")
+            ),
+            span,
+        )?;
+    } else {
+        let parsed: serde_json::Value = serde_json::from_str(line).map_err(|e| {
+            ShellError::GenericError(
+                "Failed to parse line as JSON".into(),
+                e.to_string(),
+                Some(span),
+                Some("use --raw to ingest lines without parsing".into()),
+                Vec::new(),
+            )
+        })?;
+
+        let serde_json::Value::Object(fields) = parsed else {
+            return Err(ShellError::GenericError(
+                "Expected a JSON object per line".into(),
+                format!("got {line}"),
+                Some(span),
+                Some("use --raw to ingest lines without parsing".into()),
+                Vec::new(),
+            ));
+        };
+
+        let mut record = Record::new();
+        for (key, value) in fields {
+            record.push(key, json_to_value(value, span));
+        }
+
+        // the first line creates the table if needed; after that the catalog's
+        // column set is authoritative, not whatever keys this line happens to
+        // carry
+        let columns = match columns {
+            Some(columns) => columns,
+            None => {
+                ensure_table_for_record(&conn, table_name, &record, span)?;
+                columns.insert(table_columns(&conn, table_name, span)?)
+            }
+        };
+
+        // a key no earlier line carried grows the table; keys a line omits are
+        // left to insert_record, which binds them as NULL
+        for (key, value) in record.iter() {
+            if !columns.contains(key) {
+                run_stor_execute(
+                    &conn,
+                    &format!(
+                        "ALTER TABLE {} ADD COLUMN {} {}",
+                        quote_ident(table_name),
+                        quote_ident(key),
+                        nu_value_to_duckdb_type(value)
+                    ),
+                    span,
+                )?;
+                columns.push(key.clone());
+            }
+        }
+
+        insert_record(&conn, table_name, columns, &record, span)?;
+    }
+
+    Ok(())
+}
+
+fn json_to_value(value: serde_json::Value, span: Span) -> Value {
+    match value {
+        serde_json::Value::Null => Value::nothing(span),
+        serde_json::Value::Bool(b) => Value::bool(b, span),
+        serde_json::Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                Value::int(i, span)
+            } else {
+                Value::float(n.as_f64().unwrap_or(f64::NAN), span)
+            }
+        }
+        serde_json::Value::String(s) => Value::string(s, span),
+        // nested structures are kept in their JSON text form
+        other => Value::string(other.to_string(), span),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorIngest {});
+    }
+}
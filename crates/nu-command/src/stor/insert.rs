@@ -0,0 +1,114 @@
+use super::db::{ensure_table_for_record, insert_record, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct StorInsert;
+
+impl Command for StorInsert {
+    fn name(&self) -> &str {
+        "stor insert"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::Record(vec![]), Type::Int),
+                (Type::Table(vec![]), Type::Int),
+            ])
+            .allow_variants_without_examples(true)
+            .required(
+                "table",
+                SyntaxShape::String,
+                "table the piped records are inserted into",
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Insert piped records into a table in the in-memory database."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The table is created from the first record if it does not exist yet.
+Every field is bound as a prepared-statement parameter, so values are never
+interpolated into SQL text. Returns the number of inserted rows."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Insert the current process list",
+                example: "ps | stor insert processes",
+                result: None,
+            },
+            Example {
+                description: "Insert a single record",
+                example: "{name: foo, size: 3} | stor insert items",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "insert", "table", "saving"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let table: String = call.req(engine_state, stack, 0)?;
+        let ctrlc = engine_state.ctrlc.clone();
+
+        let conn = stor_connection(span)?;
+        let mut columns: Option<Vec<String>> = None;
+        let mut inserted: i64 = 0;
+
+        for value in input {
+            if nu_utils::ctrl_c::was_pressed(&ctrlc) {
+                break;
+            }
+
+            let value_span = value.span();
+            let Value::Record { val: record, .. } = value else {
+                return Err(ShellError::OnlySupportsThisInputType {
+                    exp_input_type: "record".into(),
+                    wrong_type: value.get_type().to_string(),
+                    dst_span: span,
+                    src_span: value_span,
+                });
+            };
+
+            if columns.is_none() {
+                columns = Some(ensure_table_for_record(&conn, &table, &record, span)?);
+            }
+            let columns = columns.as_ref().expect("columns were just resolved");
+            insert_record(&conn, &table, columns, &record, span)?;
+            inserted += 1;
+        }
+
+        Ok(Value::int(inserted, span).into_pipeline_data())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorInsert {});
+    }
+}
@@ -0,0 +1,118 @@
+use super::db::{quote_ident, record_startup_sql, run_stor_execute, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct StorMacroCreate;
+
+impl Command for StorMacroCreate {
+    fn name(&self) -> &str {
+        "stor macro create"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("name", SyntaxShape::String, "name of the macro to create")
+            .required_named(
+                "as",
+                SyntaxShape::String,
+                "expression (or query for table macros) forming the macro body",
+                Some('a'),
+            )
+            .named(
+                "params",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "parameter names of the macro",
+                Some('p'),
+            )
+            .switch("table", "create a table macro instead of a scalar one", Some('t'))
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Create a DuckDB macro in the in-memory database."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The macro definition is remembered for the rest of the session and re-applied
+whenever a fresh in-memory connection is opened."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Create a scalar macro",
+                example: r#"stor macro create add_one --params [x] --as "x + 1""#,
+                result: None,
+            },
+            Example {
+                description: "Create a table macro",
+                example: r#"stor macro create top_sales --params [n] --as "SELECT * FROM sales ORDER BY amount DESC LIMIT n" --table"#,
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "macro", "function"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        let body: String = call
+            .get_flag(engine_state, stack, "as")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "as".into(),
+                span,
+            })?;
+        let params: Vec<String> = call
+            .get_flag(engine_state, stack, "params")?
+            .unwrap_or_default();
+        let table = call.has_flag("table");
+
+        let param_list = params
+            .iter()
+            .map(|p| quote_ident(p))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let sql = format!(
+            "CREATE MACRO {}({}) AS {}{}",
+            quote_ident(&name),
+            param_list,
+            if table { "TABLE " } else { "" },
+            body
+        );
+
+        let conn = stor_connection(span)?;
+        run_stor_execute(&conn, &sql, span)?;
+        record_startup_sql(&name, &sql);
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorMacroCreate {});
+    }
+}
@@ -0,0 +1,80 @@
+use super::db::{forget_startup_sql, quote_ident, run_stor_execute, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct StorMacroDrop;
+
+impl Command for StorMacroDrop {
+    fn name(&self) -> &str {
+        "stor macro drop"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("name", SyntaxShape::String, "name of the macro to drop")
+            .switch("table", "the macro is a table macro", Some('t'))
+            .switch("if-exists", "do not error if the macro does not exist", Some('i'))
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Drop a macro from the in-memory database."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Drop the macro add_one",
+            example: "stor macro drop add_one",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "macro"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        let table = call.has_flag("table");
+        let if_exists = call.has_flag("if-exists");
+
+        let sql = format!(
+            "DROP MACRO {}{}{}",
+            if table { "TABLE " } else { "" },
+            if if_exists { "IF EXISTS " } else { "" },
+            quote_ident(&name)
+        );
+
+        let conn = stor_connection(span)?;
+        run_stor_execute(&conn, &sql, span)?;
+        forget_startup_sql(&name);
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorMacroDrop {});
+    }
+}
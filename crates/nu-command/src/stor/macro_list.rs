@@ -0,0 +1,91 @@
+use super::db::{run_stor_query, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct StorMacroList;
+
+impl Command for StorMacroList {
+    fn name(&self) -> &str {
+        "stor macro list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .rest(
+                "names",
+                SyntaxShape::String,
+                "only show macros with these names",
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "List the macros defined in the in-memory database."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "List all macros",
+            example: "stor macro list",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "macro", "function"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let names: Vec<String> = call.rest(engine_state, stack, 0)?;
+        let conn = stor_connection(span)?;
+
+        let filter = if names.is_empty() {
+            String::new()
+        } else {
+            let list = names
+                .iter()
+                .map(|name| format!("'{}'", name.replace('\'', "''")))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(" AND function_name IN ({list})")
+        };
+
+        run_stor_query(
+            &conn,
+            &format!(
+                "SELECT function_name, function_type, parameters, macro_definition
+                 FROM duckdb_functions()
+                 WHERE function_type IN ('macro', 'table_macro') AND NOT internal{filter}
+                 ORDER BY function_name"
+            ),
+            span,
+        )
+        .map(IntoPipelineData::into_pipeline_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorMacroList {});
+    }
+}
@@ -0,0 +1,317 @@
+use super::db::{quote_ident, run_stor_execute, run_stor_query, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type,
+};
+
+fn ensure_matviews_table(conn: &duckdb::Connection, span: Span) -> Result<(), ShellError> {
+    run_stor_execute(
+        conn,
+        "CREATE TABLE IF NOT EXISTS nu_stor_matviews (
+            view_name VARCHAR,
+            definition VARCHAR,
+            refreshed_at BIGINT
+        )",
+        span,
+    )?;
+    Ok(())
+}
+
+fn matview_definition(
+    conn: &duckdb::Connection,
+    name: &str,
+    span: Span,
+) -> Result<String, ShellError> {
+    conn.query_row(
+        "SELECT definition FROM nu_stor_matviews WHERE view_name = ?",
+        [name],
+        |row| row.get(0),
+    )
+    .map_err(|e| {
+        ShellError::GenericError(
+            format!("No materialized view named {name}"),
+            e.to_string(),
+            Some(span),
+            Some("create one with `stor matview create`".into()),
+            Vec::new(),
+        )
+    })
+}
+
+#[derive(Clone)]
+pub struct StorMatviewCreate;
+
+impl Command for StorMatviewCreate {
+    fn name(&self) -> &str {
+        "stor matview create"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("name", SyntaxShape::String, "name of the materialized view")
+            .required_named(
+                "as",
+                SyntaxShape::String,
+                "SELECT statement the view materializes",
+                Some('a'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Create a materialized view: a table kept in sync with its query via `stor matview refresh`."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Materialize daily totals",
+            example: r#"stor matview create daily_totals --as "SELECT day, sum(amount) AS total FROM sales GROUP BY day""#,
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "materialized", "view", "refresh"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        let definition: String = call
+            .get_flag(engine_state, stack, "as")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "as".into(),
+                span,
+            })?;
+
+        let conn = stor_connection(span)?;
+        ensure_matviews_table(&conn, span)?;
+
+        run_stor_execute(
+            &conn,
+            &format!("CREATE TABLE {} AS {}", quote_ident(&name), definition),
+            span,
+        )?;
+        run_stor_execute(
+            &conn,
+            &format!(
+                "INSERT INTO nu_stor_matviews VALUES ('{}', '{}', {})",
+                name.replace('\'', "''"),
+                definition.replace('\'', "''"),
+                chrono::Utc::now().timestamp()
+            ),
+            span,
+        )?;
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[derive(Clone)]
+pub struct StorMatviewRefresh;
+
+impl Command for StorMatviewRefresh {
+    fn name(&self) -> &str {
+        "stor matview refresh"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("name", SyntaxShape::String, "materialized view to refresh")
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Re-run a materialized view's query and replace its contents."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Refresh the daily totals",
+            example: "stor matview refresh daily_totals",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "materialized", "refresh"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+
+        let conn = stor_connection(span)?;
+        ensure_matviews_table(&conn, span)?;
+        let definition = matview_definition(&conn, &name, span)?;
+
+        run_stor_execute(&conn, &format!("DELETE FROM {}", quote_ident(&name)), span)?;
+        run_stor_execute(
+            &conn,
+            &format!("INSERT INTO {} {}", quote_ident(&name), definition),
+            span,
+        )?;
+        run_stor_execute(
+            &conn,
+            &format!(
+                "UPDATE nu_stor_matviews SET refreshed_at = {} WHERE view_name = '{}'",
+                chrono::Utc::now().timestamp(),
+                name.replace('\'', "''")
+            ),
+            span,
+        )?;
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[derive(Clone)]
+pub struct StorMatviewList;
+
+impl Command for StorMatviewList {
+    fn name(&self) -> &str {
+        "stor matview list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "List the materialized views with their definitions and last refresh time."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "List all materialized views",
+            example: "stor matview list",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "materialized", "view"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let conn = stor_connection(span)?;
+        ensure_matviews_table(&conn, span)?;
+
+        run_stor_query(
+            &conn,
+            "SELECT view_name, definition, to_timestamp(refreshed_at) AS refreshed_at
+             FROM nu_stor_matviews",
+            span,
+        )
+        .map(IntoPipelineData::into_pipeline_data)
+    }
+}
+
+#[derive(Clone)]
+pub struct StorMatviewDrop;
+
+impl Command for StorMatviewDrop {
+    fn name(&self) -> &str {
+        "stor matview drop"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("name", SyntaxShape::String, "materialized view to drop")
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Drop a materialized view and its backing table."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Drop the daily totals",
+            example: "stor matview drop daily_totals",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "materialized", "view"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+
+        let conn = stor_connection(span)?;
+        ensure_matviews_table(&conn, span)?;
+        // make sure it is actually a materialized view before dropping anything
+        matview_definition(&conn, &name, span)?;
+
+        run_stor_execute(
+            &conn,
+            &format!("DROP TABLE IF EXISTS {}", quote_ident(&name)),
+            span,
+        )?;
+        run_stor_execute(
+            &conn,
+            &format!(
+                "DELETE FROM nu_stor_matviews WHERE view_name = '{}'",
+                name.replace('\'', "''")
+            ),
+            span,
+        )?;
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorMatviewCreate {});
+        test_examples(StorMatviewRefresh {});
+        test_examples(StorMatviewList {});
+        test_examples(StorMatviewDrop {});
+    }
+}
@@ -0,0 +1,296 @@
+use super::db::{quote_ident, run_stor_execute, stor_connection, table_columns};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    record, Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct StorMerge;
+
+impl Command for StorMerge {
+    fn name(&self) -> &str {
+        "stor merge"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Record(vec![]))])
+            .allow_variants_without_examples(true)
+            .required(
+                "source",
+                SyntaxShape::String,
+                "table name or SELECT query supplying the new state",
+            )
+            .required("target", SyntaxShape::String, "table to merge into")
+            .required_named(
+                "key",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "columns identifying a row in both sides",
+                Some('k'),
+            )
+            .switch(
+                "delete",
+                "also delete target rows that are absent from the source",
+                Some('d'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Merge a table or query into another table by key."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Rows missing from the target are inserted, rows whose non-key columns
+differ are updated, and with --delete rows absent from the source are
+removed. The source must have the same columns as the target. Returns the
+number of rows touched per action, so a no-op sync reports all zeroes."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Sync a mirror table from a fresh import",
+                example: "stor merge incoming users --key [id] --delete",
+                result: None,
+            },
+            Example {
+                description: "Merge a filtered query instead of a whole table",
+                example: r#"stor merge "select * from staging where valid" users --key [id]"#,
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "merge", "upsert", "sync", "mirror"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let source: String = call.req(engine_state, stack, 0)?;
+        let target: String = call.req(engine_state, stack, 1)?;
+        let keys: Vec<String> = call
+            .get_flag(engine_state, stack, "key")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "key".into(),
+                span,
+            })?;
+        let delete = call.has_flag("delete");
+
+        if keys.is_empty() {
+            return Err(ShellError::GenericError(
+                "Empty --key".into(),
+                "at least one key column is needed to match rows".into(),
+                Some(span),
+                None,
+                Vec::new(),
+            ));
+        }
+
+        let conn = stor_connection(span)?;
+        let columns = table_columns(&conn, &target, span)?;
+        if columns.is_empty() {
+            return Err(ShellError::GenericError(
+                format!("No table named {target}"),
+                "the merge target must be an existing table".into(),
+                Some(span),
+                None,
+                Vec::new(),
+            ));
+        }
+        for key in &keys {
+            if !columns.contains(key) {
+                return Err(ShellError::GenericError(
+                    format!("No column {key} in {target}"),
+                    "every key column must exist in the target table".into(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                ));
+            }
+        }
+
+        let statements = merge_statements(&target, &source_relation(&source), &columns, &keys);
+
+        let updated = match &statements.update {
+            Some(update) => run_stor_execute(&conn, update, span)?,
+            None => 0,
+        };
+        let inserted = run_stor_execute(&conn, &statements.insert, span)?;
+        let deleted = if delete {
+            run_stor_execute(&conn, &statements.delete, span)?
+        } else {
+            0
+        };
+
+        Ok(Value::record(
+            record! {
+                "inserted" => Value::int(inserted as i64, span),
+                "updated" => Value::int(updated as i64, span),
+                "deleted" => Value::int(deleted as i64, span),
+            },
+            span,
+        )
+        .into_pipeline_data())
+    }
+}
+
+// A bare name merges the whole table, anything that parses as a query
+// merges its result instead.
+fn source_relation(source: &str) -> String {
+    let lowered = source.trim_start().to_lowercase();
+    if lowered.starts_with("select") || lowered.starts_with("with") {
+        format!("({source})")
+    } else {
+        quote_ident(source)
+    }
+}
+
+// The statements realizing one merge, in execution order. `update` is None
+// when every column is a key column, since there is nothing left to change.
+struct MergeStatements {
+    update: Option<String>,
+    insert: String,
+    delete: String,
+}
+
+fn merge_statements(
+    target: &str,
+    source_rel: &str,
+    columns: &[String],
+    keys: &[String],
+) -> MergeStatements {
+    let on_keys = keys
+        .iter()
+        .map(|key| format!("t.{0} = s.{0}", quote_ident(key)))
+        .collect::<Vec<_>>()
+        .join(" AND ");
+    let data_columns: Vec<&String> = columns.iter().filter(|col| !keys.contains(col)).collect();
+
+    let update = if data_columns.is_empty() {
+        None
+    } else {
+        let assignments = data_columns
+            .iter()
+            .map(|col| format!("{0} = s.{0}", quote_ident(col)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let changed = data_columns
+            .iter()
+            .map(|col| format!("t.{0} IS DISTINCT FROM s.{0}", quote_ident(col)))
+            .collect::<Vec<_>>()
+            .join(" OR ");
+        Some(format!(
+            "UPDATE {} AS t SET {assignments} FROM {source_rel} AS s \
+             WHERE {on_keys} AND ({changed})",
+            quote_ident(target)
+        ))
+    };
+
+    let column_list = columns
+        .iter()
+        .map(|col| quote_ident(col))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let insert = format!(
+        "INSERT INTO {0} ({column_list}) SELECT {column_list} FROM {source_rel} AS s \
+         WHERE NOT EXISTS (SELECT 1 FROM {0} AS t WHERE {on_keys})",
+        quote_ident(target)
+    );
+
+    let absent = keys
+        .iter()
+        .map(|key| format!("s.{0} = {1}.{0}", quote_ident(key), quote_ident(target)))
+        .collect::<Vec<_>>()
+        .join(" AND ");
+    let delete = format!(
+        "DELETE FROM {} WHERE NOT EXISTS \
+         (SELECT 1 FROM {source_rel} AS s WHERE {absent})",
+        quote_ident(target)
+    );
+
+    MergeStatements {
+        update,
+        insert,
+        delete,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorMerge {});
+    }
+
+    fn columns(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn source_relation_quotes_bare_names_and_wraps_queries() {
+        assert_eq!(source_relation("incoming"), "\"incoming\"");
+        assert_eq!(
+            source_relation("select * from staging"),
+            "(select * from staging)"
+        );
+        assert_eq!(
+            source_relation("  WITH x AS (SELECT 1) SELECT * FROM x"),
+            "(  WITH x AS (SELECT 1) SELECT * FROM x)"
+        );
+    }
+
+    #[test]
+    fn merge_statements_cover_update_insert_delete() {
+        let statements = merge_statements(
+            "users",
+            "\"incoming\"",
+            &columns(&["id", "name", "age"]),
+            &columns(&["id"]),
+        );
+
+        assert_eq!(
+            statements.update.as_deref(),
+            Some(
+                "UPDATE \"users\" AS t SET \"name\" = s.\"name\", \"age\" = s.\"age\" \
+                 FROM \"incoming\" AS s WHERE t.\"id\" = s.\"id\" \
+                 AND (t.\"name\" IS DISTINCT FROM s.\"name\" OR t.\"age\" IS DISTINCT FROM s.\"age\")"
+            )
+        );
+        assert_eq!(
+            statements.insert,
+            "INSERT INTO \"users\" (\"id\", \"name\", \"age\") \
+             SELECT \"id\", \"name\", \"age\" FROM \"incoming\" AS s \
+             WHERE NOT EXISTS (SELECT 1 FROM \"users\" AS t WHERE t.\"id\" = s.\"id\")"
+        );
+        assert_eq!(
+            statements.delete,
+            "DELETE FROM \"users\" WHERE NOT EXISTS \
+             (SELECT 1 FROM \"incoming\" AS s WHERE s.\"id\" = \"users\".\"id\")"
+        );
+    }
+
+    #[test]
+    fn merge_statements_skip_update_when_all_columns_are_keys() {
+        let statements =
+            merge_statements("pairs", "\"incoming\"", &columns(&["a", "b"]), &columns(&["a", "b"]));
+        assert!(statements.update.is_none());
+        assert!(statements
+            .insert
+            .contains("WHERE t.\"a\" = s.\"a\" AND t.\"b\" = s.\"b\""));
+    }
+}
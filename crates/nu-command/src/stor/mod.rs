@@ -0,0 +1,262 @@
+mod adbc;
+mod alter;
+mod append;
+mod asof;
+mod cache;
+mod cached;
+mod clone;
+mod cloud;
+mod comment_list;
+mod comment_set;
+mod constraint_add;
+mod constraint_drop;
+mod constraints;
+mod count;
+mod create;
+mod databases;
+mod db;
+mod delete;
+mod describe_query;
+mod diff;
+mod drop;
+mod duckdb_file;
+mod dump_schema;
+mod exec;
+mod export;
+mod export_db;
+mod extension;
+mod functions;
+mod history;
+mod hooks;
+mod import;
+mod index_create;
+mod index_drop;
+mod index_list;
+mod ingest;
+mod insert;
+mod macro_create;
+mod macro_drop;
+mod macro_list;
+mod matview;
+mod merge;
+#[cfg(feature = "odbc")]
+mod odbc;
+mod pivot;
+mod prepare;
+mod progress;
+mod query;
+mod register_view;
+mod repl;
+mod reset;
+mod sample;
+mod schedule;
+mod schema;
+mod secret;
+mod sequence_create;
+mod sequence_list;
+mod sequence_next;
+mod serve;
+mod settings;
+mod shell_relations;
+mod sniff;
+mod stats;
+mod stor_;
+mod summarize;
+mod tables;
+mod tee;
+mod to_dataset;
+mod transaction;
+mod truncate;
+mod udf;
+mod validate;
+mod view_create;
+mod view_drop;
+mod view_list;
+
+pub use adbc::StorAdbcQuery;
+pub use alter::StorAlter;
+pub use append::StorAppend;
+pub use asof::{StorAsof, StorSnapshot};
+pub use cache::{StorCacheClear, StorCacheDisable, StorCacheEnable};
+pub use cached::{StorCachedDrop, StorCachedList};
+pub use clone::StorClone;
+pub use cloud::{StorCloudInit, StorCloudList};
+pub use comment_list::StorCommentList;
+pub use comment_set::StorCommentSet;
+pub use constraint_add::StorConstraintAdd;
+pub use constraint_drop::StorConstraintDrop;
+pub use constraints::{StorConstraints, StorForeignKeys};
+pub use count::StorCount;
+pub use create::StorCreate;
+pub use databases::StorDatabases;
+pub use db::{
+    convert_duckdb_row_to_nu_value, convert_duckdb_value_to_nu_value, convert_nu_value_to_db_param,
+    run_stor_query_with_schema, stor_connection, NuValueParam,
+};
+pub use delete::StorDelete;
+pub use describe_query::StorDescribeQuery;
+pub use diff::StorDiff;
+pub use drop::StorDrop;
+pub use duckdb_file::{DuckDBDatabase, StorOpen};
+pub use dump_schema::{StorDumpSchema, StorRestore};
+pub use exec::StorExec;
+pub use export::StorExport;
+pub use export_db::{StorExportDb, StorImportDb};
+pub use extension::{StorExtensionInstall, StorExtensionList, StorExtensionLoad};
+pub use functions::{register_scalar_function, StorScalarFunction};
+pub use history::StorHistory;
+pub use hooks::{StorHookAdd, StorHookClear};
+pub use import::StorImport;
+pub use index_create::StorIndexCreate;
+pub use index_drop::StorIndexDrop;
+pub use index_list::StorIndexList;
+pub use ingest::StorIngest;
+pub use insert::StorInsert;
+pub use macro_create::StorMacroCreate;
+pub use macro_drop::StorMacroDrop;
+pub use macro_list::StorMacroList;
+pub use matview::{StorMatviewCreate, StorMatviewDrop, StorMatviewList, StorMatviewRefresh};
+pub use merge::StorMerge;
+#[cfg(feature = "odbc")]
+pub use odbc::StorOdbcQuery;
+pub use pivot::{StorPivot, StorUnpivot};
+pub use prepare::{StorExecute, StorPrepare, StorPreparedList};
+pub use query::StorQuery;
+pub use register_view::StorRegisterView;
+pub use repl::StorRepl;
+pub use reset::StorReset;
+pub use sample::StorSample;
+pub use schedule::{StorScheduleAdd, StorScheduleList, StorScheduleRemove};
+pub use schema::StorSchema;
+pub use secret::{StorSecretCreate, StorSecretDrop, StorSecretList};
+pub use sequence_create::StorSequenceCreate;
+pub use sequence_list::StorSequenceList;
+pub use sequence_next::StorSequenceNext;
+pub use serve::StorServe;
+pub use settings::{StorSet, StorSettings};
+pub use shell_relations::refresh_shell_state;
+pub use sniff::StorSniff;
+pub use stats::StorStats;
+pub use stor_::Stor;
+pub use summarize::StorSummarize;
+pub use tables::StorTables;
+pub use tee::StorTee;
+pub use to_dataset::StorToDataset;
+pub use transaction::StorTransaction;
+pub use truncate::StorTruncate;
+pub use udf::{StorUdfList, StorUdfRegister, StorUdfRemove};
+pub use validate::StorValidate;
+pub use view_create::StorViewCreate;
+pub use view_drop::StorViewDrop;
+pub use view_list::StorViewList;
+
+use nu_protocol::engine::StateWorkingSet;
+
+pub fn add_stor_decls(working_set: &mut StateWorkingSet) {
+    macro_rules! bind_command {
+            ( $command:expr ) => {
+                working_set.add_decl(Box::new($command));
+            };
+            ( $( $command:expr ),* ) => {
+                $( working_set.add_decl(Box::new($command)); )*
+            };
+        }
+
+    bind_command!(
+        Stor,
+        StorAdbcQuery,
+        StorAlter,
+        StorAppend,
+        StorAsof,
+        StorCacheClear,
+        StorCacheDisable,
+        StorCacheEnable,
+        StorCachedDrop,
+        StorCachedList,
+        StorClone,
+        StorCloudInit,
+        StorCloudList,
+        StorCommentList,
+        StorCommentSet,
+        StorConstraintAdd,
+        StorConstraintDrop,
+        StorConstraints,
+        StorCount,
+        StorCreate,
+        StorDatabases,
+        StorDelete,
+        StorDescribeQuery,
+        StorDiff,
+        StorDrop,
+        StorDumpSchema,
+        StorExec,
+        StorExecute,
+        StorExport,
+        StorExportDb,
+        StorExtensionInstall,
+        StorExtensionList,
+        StorExtensionLoad,
+        StorForeignKeys,
+        StorHistory,
+        StorHookAdd,
+        StorHookClear,
+        StorImport,
+        StorImportDb,
+        StorIndexCreate,
+        StorIndexDrop,
+        StorIndexList,
+        StorIngest,
+        StorInsert,
+        StorMacroCreate,
+        StorMacroDrop,
+        StorMacroList,
+        StorMatviewCreate,
+        StorMatviewDrop,
+        StorMatviewList,
+        StorMatviewRefresh,
+        StorMerge,
+        StorOpen,
+        StorPivot,
+        StorPrepare,
+        StorPreparedList,
+        StorQuery,
+        StorRegisterView,
+        StorRepl,
+        StorReset,
+        StorRestore,
+        StorSample,
+        StorScheduleAdd,
+        StorScheduleList,
+        StorScheduleRemove,
+        StorSchema,
+        StorSecretCreate,
+        StorSecretDrop,
+        StorSecretList,
+        StorSequenceCreate,
+        StorSequenceList,
+        StorSequenceNext,
+        StorServe,
+        StorSet,
+        StorSettings,
+        StorSnapshot,
+        StorSniff,
+        StorStats,
+        StorSummarize,
+        StorTables,
+        StorTee,
+        StorToDataset,
+        StorTransaction,
+        StorTruncate,
+        StorUdfList,
+        StorUdfRegister,
+        StorUdfRemove,
+        StorUnpivot,
+        StorValidate,
+        StorViewCreate,
+        StorViewDrop,
+        StorViewList
+    );
+
+    #[cfg(feature = "odbc")]
+    bind_command!(StorOdbcQuery);
+}
@@ -0,0 +1,140 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, Record, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+use odbc_api::{buffers::TextRowSet, ConnectionOptions, Cursor, Environment};
+
+const BATCH_SIZE: usize = 1024;
+const MAX_TEXT_LEN: usize = 4096;
+
+#[derive(Clone)]
+pub struct StorOdbcQuery;
+
+impl Command for StorOdbcQuery {
+    fn name(&self) -> &str {
+        "stor odbc query"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .required("sql", SyntaxShape::String, "SQL to run on the ODBC data source")
+            .required_named(
+                "connection-string",
+                SyntaxShape::String,
+                "ODBC connection string, e.g. DSN=warehouse or a full driver string",
+                Some('c'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Query an enterprise data source through ODBC."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Uses the system's ODBC driver manager, so anything with an installed ODBC
+driver (SQL Server, Oracle, DB2, ...) can be queried. Cell values are
+returned in their text form."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Query a DSN configured in the system's ODBC settings",
+            example: r#"stor odbc query "SELECT TOP 10 * FROM orders" --connection-string "DSN=warehouse""#,
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "odbc", "remote", "enterprise", "dsn"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let sql: String = call.req(engine_state, stack, 0)?;
+        let connection_string: String = call
+            .get_flag(engine_state, stack, "connection-string")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "connection-string".into(),
+                span,
+            })?;
+
+        let rows = run_odbc_query(&connection_string, &sql, span)?;
+        Ok(Value::list(rows, span).into_pipeline_data())
+    }
+}
+
+fn run_odbc_query(
+    connection_string: &str,
+    sql: &str,
+    span: Span,
+) -> Result<Vec<Value>, ShellError> {
+    let odbc_error = |e: odbc_api::Error| {
+        ShellError::GenericError(
+            "ODBC error".into(),
+            e.to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    };
+
+    let environment = Environment::new().map_err(odbc_error)?;
+    let connection = environment
+        .connect_with_connection_string(connection_string, ConnectionOptions::default())
+        .map_err(odbc_error)?;
+
+    let mut rows = Vec::new();
+    if let Some(mut cursor) = connection.execute(sql, ()).map_err(odbc_error)? {
+        let column_names: Vec<String> = cursor
+            .column_names()
+            .map_err(odbc_error)?
+            .collect::<Result<_, _>>()
+            .map_err(odbc_error)?;
+
+        let mut buffers = TextRowSet::for_cursor(BATCH_SIZE, &mut cursor, Some(MAX_TEXT_LEN))
+            .map_err(odbc_error)?;
+        let mut row_set_cursor = cursor.bind_buffer(&mut buffers).map_err(odbc_error)?;
+
+        while let Some(batch) = row_set_cursor.fetch().map_err(odbc_error)? {
+            for row in 0..batch.num_rows() {
+                let mut record = Record::new();
+                for (col, name) in column_names.iter().enumerate() {
+                    let value = match batch.at(col, row) {
+                        Some(bytes) => {
+                            Value::string(String::from_utf8_lossy(bytes).to_string(), span)
+                        }
+                        None => Value::nothing(span),
+                    };
+                    record.push(name.clone(), value);
+                }
+                rows.push(Value::record(record, span));
+            }
+        }
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorOdbcQuery {});
+    }
+}
@@ -0,0 +1,198 @@
+use super::db::{quote_ident, run_stor_query, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct StorPivot;
+
+impl Command for StorPivot {
+    fn name(&self) -> &str {
+        "stor pivot"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .required("table", SyntaxShape::String, "table to pivot")
+            .required_named(
+                "on",
+                SyntaxShape::String,
+                "column whose values become the new columns",
+                Some('o'),
+            )
+            .named(
+                "using",
+                SyntaxShape::String,
+                "aggregate expression filling the cells (default count(*))",
+                Some('u'),
+            )
+            .named(
+                "group-by",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "columns to keep as row labels",
+                Some('g'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Pivot a table with DuckDB's native PIVOT."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Reshaping happens inside the database engine, which is much faster
+than pulling the rows into nu and reshaping there."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Count log levels per day",
+            example: "stor pivot logs --on level --group-by [day]",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "pivot", "reshape", "crosstab"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let table: String = call.req(engine_state, stack, 0)?;
+        let on: String = call
+            .get_flag(engine_state, stack, "on")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "on".into(),
+                span,
+            })?;
+        let using: Option<String> = call.get_flag(engine_state, stack, "using")?;
+        let group_by: Option<Vec<String>> = call.get_flag(engine_state, stack, "group-by")?;
+
+        let mut sql = format!(
+            "PIVOT {} ON {} USING {}",
+            quote_ident(&table),
+            quote_ident(&on),
+            using.unwrap_or_else(|| "count(*)".into())
+        );
+        if let Some(group_by) = group_by {
+            let columns = group_by
+                .iter()
+                .map(|col| quote_ident(col))
+                .collect::<Vec<_>>()
+                .join(", ");
+            sql.push_str(&format!(" GROUP BY {columns}"));
+        }
+
+        let conn = stor_connection(span)?;
+        run_stor_query(&conn, &sql, span).map(IntoPipelineData::into_pipeline_data)
+    }
+}
+
+#[derive(Clone)]
+pub struct StorUnpivot;
+
+impl Command for StorUnpivot {
+    fn name(&self) -> &str {
+        "stor unpivot"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .required("table", SyntaxShape::String, "table to unpivot")
+            .required_named(
+                "on",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "columns to fold into name/value rows",
+                Some('o'),
+            )
+            .named(
+                "name",
+                SyntaxShape::String,
+                "name of the column holding the folded column names (default name)",
+                Some('n'),
+            )
+            .named(
+                "value",
+                SyntaxShape::String,
+                "name of the column holding the folded values (default value)",
+                Some('v'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Unpivot columns into rows with DuckDB's native UNPIVOT."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Fold the month columns into rows",
+            example: "stor unpivot sales --on [jan feb mar] --name month --value total",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "unpivot", "melt", "fold", "reshape"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let table: String = call.req(engine_state, stack, 0)?;
+        let on: Vec<String> = call
+            .get_flag(engine_state, stack, "on")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "on".into(),
+                span,
+            })?;
+        let name: Option<String> = call.get_flag(engine_state, stack, "name")?;
+        let value: Option<String> = call.get_flag(engine_state, stack, "value")?;
+
+        let columns = on
+            .iter()
+            .map(|col| quote_ident(col))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let sql = format!(
+            "UNPIVOT {} ON {columns} INTO NAME {} VALUE {}",
+            quote_ident(&table),
+            quote_ident(&name.unwrap_or_else(|| "name".into())),
+            quote_ident(&value.unwrap_or_else(|| "value".into()))
+        );
+
+        let conn = stor_connection(span)?;
+        run_stor_query(&conn, &sql, span).map(IntoPipelineData::into_pipeline_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorPivot {});
+        test_examples(StorUnpivot {});
+    }
+}
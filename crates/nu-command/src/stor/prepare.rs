@@ -0,0 +1,279 @@
+use super::db::{
+    forget_startup_sql, record_startup_sql, run_stor_execute, run_stor_query_params,
+    stor_connection,
+};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    record, Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+use once_cell::sync::Lazy;
+use std::sync::Mutex;
+
+// The statements prepared this session, by name. DuckDB itself owns the
+// parsed handles (PREPARE/EXECUTE are plain SQL); this registry only exists
+// so `stor prepared list` can show the original text, and so the PREPARE
+// replays if the in-memory connection is ever reset.
+static PREPARED: Lazy<Mutex<Vec<(String, String)>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+// Prepared-statement names become part of the PREPARE/EXECUTE statements, so
+// only plain identifiers are accepted.
+fn validated_statement_name(name: &str, span: Span) -> Result<String, ShellError> {
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+    {
+        Ok(name.to_string())
+    } else {
+        Err(ShellError::GenericError(
+            format!("Invalid statement name {name}"),
+            "statement names must be plain identifiers".into(),
+            Some(span),
+            None,
+            Vec::new(),
+        ))
+    }
+}
+
+#[derive(Clone)]
+pub struct StorPrepare;
+
+impl Command for StorPrepare {
+    fn name(&self) -> &str {
+        "stor prepare"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("name", SyntaxShape::String, "name for the statement")
+            .required(
+                "query",
+                SyntaxShape::String,
+                "SQL to prepare, with ? or $1-style placeholders",
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Parse a statement once for repeated execution with `stor execute`."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "A hot query in a loop pays the parse and plan cost once instead of
+per call. Preparing a name again replaces the previous statement; the
+handle lives alongside the session connection until the session ends."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Prepare a lookup used in a loop",
+            example: r#"stor prepare by_id "select * from users where id = ?""#,
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "prepare", "statement", "plan"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        let query: String = call.req(engine_state, stack, 1)?;
+        let name = validated_statement_name(&name, span)?;
+
+        let sql = format!("PREPARE {name} AS {query}");
+        let conn = stor_connection(span)?;
+        run_stor_execute(&conn, &sql, span)?;
+        record_startup_sql(&format!("prepare {name}"), &sql);
+
+        if let Ok(mut prepared) = PREPARED.lock() {
+            prepared.retain(|(existing, _)| existing != &name);
+            prepared.push((name, query));
+        }
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[derive(Clone)]
+pub struct StorExecute;
+
+impl Command for StorExecute {
+    fn name(&self) -> &str {
+        "stor execute"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Any)])
+            .allow_variants_without_examples(true)
+            .required(
+                "name",
+                SyntaxShape::String,
+                "name given to `stor prepare`",
+            )
+            .named(
+                "params",
+                SyntaxShape::List(Box::new(SyntaxShape::Any)),
+                "values for the statement's placeholders, in order",
+                Some('p'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Run a prepared statement, binding parameters."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Execute a prepared lookup for several ids",
+            example: "[1 2 3] | each { |id| stor execute by_id --params [$id] }",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "execute", "prepared", "statement"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        let params: Vec<Value> = call
+            .get_flag(engine_state, stack, "params")?
+            .unwrap_or_default();
+        let name = validated_statement_name(&name, span)?;
+
+        let sql = if params.is_empty() {
+            format!("EXECUTE {name}")
+        } else {
+            let placeholders = vec!["?"; params.len()].join(", ");
+            format!("EXECUTE {name}({placeholders})")
+        };
+
+        let conn = stor_connection(span)?;
+        run_stor_query_params(&conn, &sql, &params, span)
+            .map(IntoPipelineData::into_pipeline_data)
+    }
+}
+
+#[derive(Clone)]
+pub struct StorPreparedList;
+
+impl Command for StorPreparedList {
+    fn name(&self) -> &str {
+        "stor prepared list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .named(
+                "remove",
+                SyntaxShape::String,
+                "deallocate this prepared statement instead of listing",
+                Some('r'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "List the statements prepared in this session."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "See what is prepared",
+                example: "stor prepared list",
+                result: None,
+            },
+            Example {
+                description: "Drop a prepared statement",
+                example: "stor prepared list --remove by_id",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "prepared", "statement", "list"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let remove: Option<String> = call.get_flag(engine_state, stack, "remove")?;
+
+        if let Some(name) = remove {
+            let name = validated_statement_name(&name, span)?;
+            let conn = stor_connection(span)?;
+            run_stor_execute(&conn, &format!("DEALLOCATE {name}"), span)?;
+            forget_startup_sql(&format!("prepare {name}"));
+            if let Ok(mut prepared) = PREPARED.lock() {
+                prepared.retain(|(existing, _)| existing != &name);
+            }
+            return Ok(PipelineData::empty());
+        }
+
+        let rows = PREPARED
+            .lock()
+            .map(|prepared| {
+                prepared
+                    .iter()
+                    .map(|(name, sql)| {
+                        Value::record(
+                            record! {
+                                "name" => Value::string(name.clone(), span),
+                                "sql" => Value::string(sql.clone(), span),
+                            },
+                            span,
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(Value::list(rows, span).into_pipeline_data())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorPrepare {});
+        test_examples(StorExecute {});
+        test_examples(StorPreparedList {});
+    }
+}
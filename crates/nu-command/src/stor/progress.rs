@@ -0,0 +1,47 @@
+use indicatif::{ProgressBar, ProgressStyle};
+
+// Item-oriented progress reporting for the long-running stor operations
+// (exports, ingests, multi-table maintenance). Modeled on the byte-oriented
+// bar in crate::progress_bar but counting rows/tables instead of bytes.
+// indicatif hides the bar automatically when stderr is not a terminal.
+pub(super) struct StorProgress {
+    pb: ProgressBar,
+}
+
+impl StorProgress {
+    pub fn new(total: Option<u64>, message: &str) -> StorProgress {
+        let template = match total {
+            Some(_) => ProgressStyle::with_template(
+                "{spinner:.green} [{elapsed_precise}] [{bar:30.cyan/blue}] [{pos}/{len}] {wide_msg}",
+            ),
+            None => ProgressStyle::with_template(
+                "{spinner:.green} [{elapsed_precise}] {pos} {wide_msg}",
+            ),
+        };
+
+        let pb = match total {
+            Some(total) => ProgressBar::new(total),
+            None => ProgressBar::new_spinner(),
+        };
+        pb.set_style(
+            template
+                .unwrap_or_else(|_| ProgressStyle::default_bar())
+                .progress_chars("#>-"),
+        );
+        pb.set_message(message.to_string());
+
+        StorProgress { pb }
+    }
+
+    pub fn inc(&self) {
+        self.pb.inc(1);
+    }
+
+    pub fn set_message(&self, message: String) {
+        self.pb.set_message(message);
+    }
+
+    pub fn finish(&self) {
+        self.pb.finish_and_clear();
+    }
+}
@@ -0,0 +1,418 @@
+use super::cached::{register_cached, validated_cache_name};
+use super::db::{
+    register_ctrlc, run_stor_execute, run_stor_query, run_stor_query_params,
+    run_stor_query_with_schema, split_sql_statements, stor_connection, OutputConversionGuard,
+    ProgressBarGuard, QueryTimeoutGuard,
+};
+use super::shell_relations::refresh_shell_state;
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    record, Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct StorQuery;
+
+impl Command for StorQuery {
+    fn name(&self) -> &str {
+        "stor query"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Any)])
+            .allow_variants_without_examples(true)
+            .optional(
+                "query",
+                SyntaxShape::String,
+                "SQL to run against the in-memory database",
+            )
+            .named(
+                "file",
+                SyntaxShape::Filepath,
+                "run the statements in this .sql file instead",
+                Some('f'),
+            )
+            .named(
+                "cache",
+                SyntaxShape::String,
+                "materialize the result into a temp table of this name",
+                None,
+            )
+            .named(
+                "params",
+                SyntaxShape::Any,
+                "list of values for ? placeholders, or a record for $name placeholders",
+                Some('p'),
+            )
+            .switch(
+                "progress",
+                "show DuckDB's percent-complete progress bar while the query runs",
+                None,
+            )
+            .switch(
+                "with-schema",
+                "return {schema, rows} instead of the bare rows",
+                None,
+            )
+            .switch(
+                "decimal-as-string",
+                "return DECIMAL columns as exact strings instead of lossy floats",
+                None,
+            )
+            .switch(
+                "nan-as-null",
+                "map NaN and infinite floats in the results to nothing",
+                None,
+            )
+            .named(
+                "timeout",
+                SyntaxShape::Duration,
+                "interrupt the query if it runs longer than this",
+                None,
+            )
+            .named(
+                "type-map",
+                SyntaxShape::Record(vec![]),
+                "override result conversions per source type, e.g. {blob: string}",
+                None,
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Query the in-memory database, optionally binding parameters."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Values passed via --params are bound as real prepared-statement
+parameters, so user data never needs to be interpolated into the SQL text.
+Positional parameters use ? with a list, named parameters use $name with a
+record."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Query with positional parameters",
+                example: r#"stor query "select * from logs where level = ?" --params [error]"#,
+                result: None,
+            },
+            Example {
+                description: "Query with named parameters",
+                example: r#"stor query "select * from t where id = $id" --params {id: 42}"#,
+                result: None,
+            },
+            Example {
+                description: "Return the result schema alongside the rows",
+                example: r#"stor query "select now()" --with-schema"#,
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "query", "sql", "select", "parameters"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let sql: Option<String> = call.opt(engine_state, stack, 0)?;
+        let file: Option<Spanned<String>> = call.get_flag(engine_state, stack, "file")?;
+        let params: Option<Value> = call.get_flag(engine_state, stack, "params")?;
+        let with_schema = call.has_flag("with-schema");
+
+        register_ctrlc(&engine_state.ctrlc);
+        refresh_shell_state(engine_state, stack);
+
+        let timeout: Option<Value> = call.get_flag(engine_state, stack, "timeout")?;
+        let _timeout = QueryTimeoutGuard::new(match timeout {
+            Some(timeout) => {
+                let nanos = timeout.as_duration()?;
+                Some(std::time::Duration::from_nanos(nanos.max(0) as u64))
+            }
+            None => None,
+        });
+
+        let type_map: Option<Value> = call.get_flag(engine_state, stack, "type-map")?;
+        let _conversions = OutputConversionGuard::new(
+            call.has_flag("decimal-as-string"),
+            call.has_flag("nan-as-null"),
+            match &type_map {
+                Some(overrides) => {
+                    let record = overrides.as_record()?;
+                    record
+                        .iter()
+                        .map(|(from, to)| Ok((from.clone(), to.as_string()?)))
+                        .collect::<Result<Vec<_>, ShellError>>()?
+                }
+                None => Vec::new(),
+            },
+        );
+
+        let conn = stor_connection(span)?;
+        let _progress = ProgressBarGuard::new(&conn, call.has_flag("progress"), span)?;
+
+        let sql = match (sql, file) {
+            (Some(sql), None) => sql,
+            (None, Some(file)) => {
+                if params.is_some() {
+                    return Err(ShellError::GenericError(
+                        "--params cannot be combined with --file".into(),
+                        "script files run without bound parameters".into(),
+                        Some(span),
+                        None,
+                        Vec::new(),
+                    ));
+                }
+                return run_script_file(&conn, &file, span)
+                    .map(IntoPipelineData::into_pipeline_data);
+            }
+            _ => {
+                return Err(ShellError::GenericError(
+                    "Nothing to run".into(),
+                    "pass either a query or --file, not both or neither".into(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                ))
+            }
+        };
+
+        if let Some(cache) = call.get_flag::<String>(engine_state, stack, "cache")? {
+            if params.is_some() || with_schema {
+                return Err(ShellError::GenericError(
+                    "--cache cannot be combined with --params or --with-schema".into(),
+                    "materialized results are plain temp tables".into(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                ));
+            }
+            let cache = validated_cache_name(&cache, span)?;
+            run_stor_execute(
+                &conn,
+                &format!("CREATE OR REPLACE TEMP TABLE {cache} AS {sql}"),
+                span,
+            )?;
+            register_cached(&cache, &sql);
+            let rows: i64 = conn
+                .query_row(&format!("SELECT count(*) FROM {cache}"), [], |row| {
+                    row.get(0)
+                })
+                .unwrap_or_default();
+            return Ok(Value::record(
+                record! {
+                    "table" => Value::string(cache, span),
+                    "rows" => Value::int(rows, span),
+                },
+                span,
+            )
+            .into_pipeline_data());
+        }
+
+        let (sql, params) = match params {
+            Some(params) => bind_params(&sql, &params, span)?,
+            None => (sql, Vec::new()),
+        };
+        let result = if with_schema {
+            if !params.is_empty() {
+                return Err(ShellError::GenericError(
+                    "--with-schema cannot be combined with --params".into(),
+                    "schema inspection runs the query without bound parameters".into(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                ));
+            }
+            run_stor_query_with_schema(&conn, &sql, span)
+        } else if params.is_empty() {
+            run_stor_query(&conn, &sql, span)
+        } else {
+            run_stor_query_params(&conn, &sql, &params, span)
+        };
+
+        result.map(IntoPipelineData::into_pipeline_data)
+    }
+}
+
+// Run every statement of a script file in order. If the script ends in a
+// statement that returns rows, those rows are the result; otherwise a
+// per-statement summary of affected rows is returned.
+fn run_script_file(
+    conn: &duckdb::Connection,
+    file: &Spanned<String>,
+    span: Span,
+) -> Result<Value, ShellError> {
+    let script = std::fs::read_to_string(&file.item).map_err(|e| {
+        ShellError::GenericError(
+            format!("Failed to read {}", file.item),
+            e.to_string(),
+            Some(file.span),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    let statements = split_sql_statements(&script);
+    let mut summary = Vec::new();
+
+    for (i, statement) in statements.iter().enumerate() {
+        let last = i + 1 == statements.len();
+        let lowered = statement.to_lowercase();
+        let returns_rows = ["select", "with", "pivot", "unpivot", "summarize", "from"]
+            .iter()
+            .any(|kw| lowered.starts_with(kw));
+
+        if last && returns_rows {
+            return run_stor_query(conn, statement, span);
+        }
+
+        let rows_affected = run_stor_execute(conn, statement, span)?;
+        summary.push(Value::record(
+            record! {
+                "statement" => Value::string(statement.clone(), span),
+                "rows_affected" => Value::int(rows_affected as i64, span),
+            },
+            span,
+        ));
+    }
+
+    Ok(Value::list(summary, span))
+}
+
+// Resolve --params into positional values: a list binds to ? placeholders
+// as-is, a record rewrites each $name in the SQL to ? (in order of
+// appearance, skipping quoted text) and binds the matching record value.
+fn bind_params(sql: &str, params: &Value, span: Span) -> Result<(String, Vec<Value>), ShellError> {
+    match params {
+        Value::List { vals, .. } => Ok((sql.to_string(), vals.clone())),
+        Value::Record { val, .. } => {
+            let mut rewritten = String::with_capacity(sql.len());
+            let mut positional = Vec::new();
+            let mut chars = sql.chars().peekable();
+            let mut in_string = false;
+
+            while let Some(c) = chars.next() {
+                if c == '\'' {
+                    in_string = !in_string;
+                    rewritten.push(c);
+                } else if c == '$' && !in_string {
+                    let mut name = String::new();
+                    while let Some(&next) = chars.peek() {
+                        if next.is_alphanumeric() || next == '_' {
+                            name.push(next);
+                            chars.next();
+                        } else {
+                            break;
+                        }
+                    }
+                    match val.iter().find(|(col, _)| **col == name) {
+                        Some((_, value)) => {
+                            rewritten.push('?');
+                            positional.push(value.clone());
+                        }
+                        None => {
+                            return Err(ShellError::GenericError(
+                                format!("No value for parameter ${name}"),
+                                "named parameter without a matching record key".into(),
+                                Some(span),
+                                Some(format!("add a {name} column to --params")),
+                                Vec::new(),
+                            ))
+                        }
+                    }
+                } else {
+                    rewritten.push(c);
+                }
+            }
+
+            Ok((rewritten, positional))
+        }
+        other => Err(ShellError::GenericError(
+            "Invalid --params value".into(),
+            format!("expected a list or record, got {}", other.get_type()),
+            Some(span),
+            None,
+            Vec::new(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorQuery {});
+    }
+
+    #[test]
+    fn list_params_pass_through_unchanged() {
+        let params = Value::list(
+            vec![Value::test_int(1), Value::test_string("a")],
+            Span::test_data(),
+        );
+        let (sql, positional) =
+            bind_params("select * from t where a = ? and b = ?", &params, Span::test_data())
+                .expect("list params bind");
+        assert_eq!(sql, "select * from t where a = ? and b = ?");
+        assert_eq!(positional, vec![Value::test_int(1), Value::test_string("a")]);
+    }
+
+    #[test]
+    fn record_params_rewrite_each_name_in_order() {
+        let params = Value::test_record(record! {
+            "id" => Value::test_int(42),
+            "level" => Value::test_string("error"),
+        });
+        let (sql, positional) = bind_params(
+            "select * from t where level = $level and id = $id",
+            &params,
+            Span::test_data(),
+        )
+        .expect("record params bind");
+        assert_eq!(sql, "select * from t where level = ? and id = ?");
+        assert_eq!(
+            positional,
+            vec![Value::test_string("error"), Value::test_int(42)]
+        );
+    }
+
+    #[test]
+    fn record_params_skip_quoted_text() {
+        let params = Value::test_record(record! {
+            "id" => Value::test_int(1),
+        });
+        let (sql, positional) = bind_params(
+            "select '$id' as literal from t where id = $id",
+            &params,
+            Span::test_data(),
+        )
+        .expect("record params bind");
+        assert_eq!(sql, "select '$id' as literal from t where id = ?");
+        assert_eq!(positional, vec![Value::test_int(1)]);
+    }
+
+    #[test]
+    fn record_params_error_on_missing_keys() {
+        let params = Value::test_record(record! {
+            "id" => Value::test_int(1),
+        });
+        let error = bind_params("select $missing", &params, Span::test_data())
+            .expect_err("unmatched parameter should fail");
+        assert!(error.to_string().contains("$missing"));
+    }
+}
@@ -0,0 +1,137 @@
+use super::cached::validated_cache_name;
+use super::db::{ensure_table_for_record, insert_record, run_stor_execute, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    record, Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct StorRegisterView;
+
+impl Command for StorRegisterView {
+    fn name(&self) -> &str {
+        "stor register-view"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::Record(vec![]), Type::Record(vec![])),
+                (Type::Table(vec![]), Type::Record(vec![])),
+            ])
+            .allow_variants_without_examples(true)
+            .required(
+                "name",
+                SyntaxShape::String,
+                "view name the piped rows become queryable under",
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Make piped records queryable from SQL under the given name."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The rows load into a hidden staging table and a temp view of the
+given name is defined over it, so shell data can be joined against parquet
+files or store tables in one `stor query` without writing intermediate
+files. Registering the same name again replaces the previous data."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Join the process list against logged sessions",
+            example: r#"ps | stor register-view procs; stor query "select * from sessions join procs using (pid)""#,
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "register", "view", "join", "bridge"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        let name = validated_cache_name(&name, span)?;
+        let ctrlc = engine_state.ctrlc.clone();
+
+        // The nu_stor_ prefix keeps the staging table out of `stor tables`.
+        let staging = format!("nu_stor_data_{name}");
+        let conn = stor_connection(span)?;
+        run_stor_execute(&conn, &format!("DROP VIEW IF EXISTS {name}"), span)?;
+        run_stor_execute(&conn, &format!("DROP TABLE IF EXISTS {staging}"), span)?;
+
+        let mut columns: Option<Vec<String>> = None;
+        let mut rows: i64 = 0;
+        for value in input {
+            if nu_utils::ctrl_c::was_pressed(&ctrlc) {
+                break;
+            }
+
+            let value_span = value.span();
+            let Value::Record { val: record, .. } = value else {
+                return Err(ShellError::OnlySupportsThisInputType {
+                    exp_input_type: "record".into(),
+                    wrong_type: value.get_type().to_string(),
+                    dst_span: span,
+                    src_span: value_span,
+                });
+            };
+
+            if columns.is_none() {
+                columns = Some(ensure_table_for_record(&conn, &staging, &record, span)?);
+            }
+            let columns = columns.as_ref().expect("columns were just resolved");
+            insert_record(&conn, &staging, columns, &record, span)?;
+            rows += 1;
+        }
+
+        if columns.is_none() {
+            return Err(ShellError::GenericError(
+                "Nothing to register".into(),
+                "pipe a record or table in".into(),
+                Some(span),
+                None,
+                Vec::new(),
+            ));
+        }
+
+        run_stor_execute(
+            &conn,
+            &format!("CREATE TEMP VIEW {name} AS SELECT * FROM {staging}"),
+            span,
+        )?;
+
+        Ok(Value::record(
+            record! {
+                "view" => Value::string(name, span),
+                "rows" => Value::int(rows, span),
+            },
+            span,
+        )
+        .into_pipeline_data())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorRegisterView {});
+    }
+}
@@ -0,0 +1,172 @@
+use super::db::{
+    register_ctrlc, run_stor_execute, run_stor_query, split_sql_statements, stor_connection,
+};
+use dialoguer::Input;
+use nu_color_config::StyleComputer;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+use nu_table::{JustTable, TableOpts};
+use terminal_size::{Height, Width};
+
+#[derive(Clone)]
+pub struct StorRepl;
+
+impl Command for StorRepl {
+    fn name(&self) -> &str {
+        "stor repl"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Open an interactive SQL prompt against the in-memory database."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Statements span lines until a closing ; and results render as nu
+tables. Type .quit (or .exit) to return to the shell. This mirrors the
+duckdb CLI but runs against the session's stor connection."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Poke at the store interactively",
+            example: "stor repl",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "repl", "interactive", "prompt", "sql"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        register_ctrlc(&engine_state.ctrlc);
+
+        let config = engine_state.get_config();
+        let style_computer = StyleComputer::from_config(engine_state, stack);
+        let width = if let Some((Width(w), Height(_))) = terminal_size::terminal_size() {
+            w as usize
+        } else {
+            80
+        };
+
+        let mut buffer = String::new();
+        loop {
+            let prompt = if buffer.is_empty() { "stor" } else { " ..." };
+            let line: String = match Input::new()
+                .with_prompt(prompt)
+                .allow_empty(true)
+                .interact_text()
+            {
+                Ok(line) => line,
+                // EOF or a non-interactive stdin: leave the repl quietly.
+                Err(_) => break,
+            };
+
+            let trimmed = line.trim();
+            if buffer.is_empty() && (trimmed == ".quit" || trimmed == ".exit") {
+                break;
+            }
+            buffer.push_str(&line);
+            buffer.push('\n');
+            if !buffer.trim_end().ends_with(';') {
+                continue;
+            }
+
+            let statements = split_sql_statements(&buffer);
+            buffer.clear();
+            for statement in statements {
+                if let Err(err) = run_statement(
+                    engine_state,
+                    &statement,
+                    config,
+                    &style_computer,
+                    width,
+                    span,
+                ) {
+                    eprintln!("Error: {err}");
+                    break;
+                }
+            }
+        }
+
+        Ok(PipelineData::empty())
+    }
+}
+
+// Run a single statement and print its result, as a table for row-returning
+// statements and as an affected-row count for everything else.
+fn run_statement(
+    engine_state: &EngineState,
+    statement: &str,
+    config: &nu_protocol::Config,
+    style_computer: &StyleComputer,
+    width: usize,
+    span: Span,
+) -> Result<(), ShellError> {
+    let conn = stor_connection(span)?;
+    let lowered = statement.trim_start().to_lowercase();
+    let returns_rows = [
+        "select", "with", "pivot", "unpivot", "summarize", "from", "show",
+    ]
+    .iter()
+    .any(|kw| lowered.starts_with(kw));
+
+    if returns_rows {
+        let result = run_stor_query(&conn, statement, span)?;
+        let Value::List { vals, .. } = result else {
+            println!("{}", result.into_string(", ", config));
+            return Ok(());
+        };
+        if vals.is_empty() {
+            println!("(no rows)");
+            return Ok(());
+        }
+        let opts = TableOpts::new(
+            config,
+            style_computer,
+            engine_state.ctrlc.clone(),
+            span,
+            0,
+            width,
+            (config.table_indent.left, config.table_indent.right),
+        );
+        match JustTable::table(&vals, opts)? {
+            Some(table) => println!("{table}"),
+            None => println!("Couldn't fit table into {width} columns!"),
+        }
+    } else {
+        let rows_affected = run_stor_execute(&conn, statement, span)?;
+        println!("{rows_affected} rows affected");
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorRepl {});
+    }
+}
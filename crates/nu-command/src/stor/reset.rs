@@ -0,0 +1,137 @@
+use super::db::{quote_ident, run_stor_execute, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct StorReset;
+
+impl Command for StorReset {
+    fn name(&self) -> &str {
+        "stor reset"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .named(
+                "table",
+                SyntaxShape::String,
+                "drop just this table instead of the whole store",
+                Some('t'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Wipe the in-memory database."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Drops every table, view and sequence. Registered macros, UDFs and
+session settings survive a reset. Use `stor truncate` instead to empty a
+table while keeping it."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Start over with an empty store",
+                example: "stor reset",
+                result: None,
+            },
+            Example {
+                description: "Drop a single table",
+                example: "stor reset --table scratch",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "reset", "wipe", "clear", "drop"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let table: Option<String> = call.get_flag(engine_state, stack, "table")?;
+
+        let conn = stor_connection(span)?;
+
+        if let Some(table) = table {
+            run_stor_execute(
+                &conn,
+                &format!("DROP TABLE IF EXISTS {} CASCADE", quote_ident(&table)),
+                span,
+            )?;
+            return Ok(PipelineData::empty());
+        }
+
+        for view in object_names(&conn, "SELECT view_name FROM duckdb_views() WHERE NOT internal", span)? {
+            run_stor_execute(
+                &conn,
+                &format!("DROP VIEW IF EXISTS {} CASCADE", quote_ident(&view)),
+                span,
+            )?;
+        }
+        for table in object_names(&conn, "SELECT table_name FROM duckdb_tables()", span)? {
+            run_stor_execute(
+                &conn,
+                &format!("DROP TABLE IF EXISTS {} CASCADE", quote_ident(&table)),
+                span,
+            )?;
+        }
+        for sequence in object_names(&conn, "SELECT sequence_name FROM duckdb_sequences()", span)? {
+            run_stor_execute(
+                &conn,
+                &format!("DROP SEQUENCE IF EXISTS {} CASCADE", quote_ident(&sequence)),
+                span,
+            )?;
+        }
+
+        Ok(PipelineData::empty())
+    }
+}
+
+fn object_names(
+    conn: &duckdb::Connection,
+    sql: &str,
+    span: Span,
+) -> Result<Vec<String>, ShellError> {
+    conn.prepare(sql)
+        .and_then(|mut stmt| {
+            stmt.query_map([], |row| row.get(0))
+                .and_then(|rows| rows.collect())
+        })
+        .map_err(|e| {
+            ShellError::GenericError(
+                "Failed to list objects to drop".into(),
+                e.to_string(),
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorReset {});
+    }
+}
@@ -0,0 +1,114 @@
+use super::db::{quote_ident, run_stor_query, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct StorSample;
+
+impl Command for StorSample {
+    fn name(&self) -> &str {
+        "stor sample"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .required("table", SyntaxShape::String, "table to sample from")
+            .named(
+                "rows",
+                SyntaxShape::Int,
+                "number of rows to sample (default 100)",
+                Some('r'),
+            )
+            .named(
+                "percent",
+                SyntaxShape::Number,
+                "percentage of the table to sample instead of a row count",
+                Some('p'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Return a random sample of a table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Wraps DuckDB's `USING SAMPLE`, so huge tables can be eyeballed without
+a full scan or an accidental `select *`."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Peek at 100 random rows",
+                example: "stor sample events",
+                result: None,
+            },
+            Example {
+                description: "Sample one percent of a table",
+                example: "stor sample events --percent 1",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "sample", "random", "peek"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let table: String = call.req(engine_state, stack, 0)?;
+        let rows: Option<i64> = call.get_flag(engine_state, stack, "rows")?;
+        let percent: Option<f64> = call.get_flag(engine_state, stack, "percent")?;
+
+        let sample = match (rows, percent) {
+            (Some(_), Some(_)) => {
+                return Err(ShellError::GenericError(
+                    "--rows and --percent are mutually exclusive".into(),
+                    "pick one".into(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                ))
+            }
+            (_, Some(percent)) => format!("{percent} PERCENT (system)"),
+            (rows, None) => format!("{} ROWS", rows.unwrap_or(100)),
+        };
+
+        let conn = stor_connection(span)?;
+        run_stor_query(
+            &conn,
+            &format!(
+                "SELECT * FROM {} USING SAMPLE {sample}",
+                quote_ident(&table)
+            ),
+            span,
+        )
+        .map(IntoPipelineData::into_pipeline_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorSample {});
+    }
+}
@@ -0,0 +1,308 @@
+use super::db::stor_connection;
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    record, Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::{
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    Arc, Mutex,
+};
+use std::time::Duration;
+
+// Background schedules, keyed by name. Each entry owns the cancel flag its
+// worker thread polls; dropping an entry from the map stops the thread at the
+// next tick.
+static SCHEDULES: Lazy<Mutex<HashMap<String, Schedule>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+struct Schedule {
+    sql: String,
+    every_ns: i64,
+    runs: Arc<AtomicU64>,
+    failures: Arc<AtomicU64>,
+    cancel: Arc<AtomicBool>,
+}
+
+#[derive(Clone)]
+pub struct StorScheduleAdd;
+
+impl Command for StorScheduleAdd {
+    fn name(&self) -> &str {
+        "stor schedule add"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("name", SyntaxShape::String, "name of the schedule")
+            .required("sql", SyntaxShape::String, "SQL statement to run on every tick")
+            .required_named(
+                "every",
+                SyntaxShape::Duration,
+                "interval between runs",
+                Some('e'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Run a SQL statement against the in-memory database on a fixed interval."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The statement runs on a background thread until the schedule is removed or
+the shell exits. Failures are counted and visible in `stor schedule list`."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Refresh a summary table every five minutes",
+            example: r#"stor schedule add refresh_totals "INSERT INTO totals SELECT now(), count(*) FROM sales" --every 5min"#,
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "schedule", "cron", "interval"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        let sql: String = call.req(engine_state, stack, 1)?;
+        let every: Value = call
+            .get_flag(engine_state, stack, "every")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "every".into(),
+                span,
+            })?;
+        let every_ns = every.as_duration()?;
+
+        if every_ns <= 0 {
+            return Err(ShellError::GenericError(
+                "Schedule interval must be positive".into(),
+                format!("got {every_ns}ns"),
+                Some(span),
+                None,
+                Vec::new(),
+            ));
+        }
+
+        let mut schedules = lock_schedules(span)?;
+        if schedules.contains_key(&name) {
+            return Err(ShellError::GenericError(
+                format!("A schedule named {name} already exists"),
+                "schedule names must be unique".into(),
+                Some(span),
+                Some("remove it first with `stor schedule remove`".into()),
+                Vec::new(),
+            ));
+        }
+
+        let runs = Arc::new(AtomicU64::new(0));
+        let failures = Arc::new(AtomicU64::new(0));
+        let cancel = Arc::new(AtomicBool::new(false));
+
+        let worker_sql = sql.clone();
+        let worker_runs = runs.clone();
+        let worker_failures = failures.clone();
+        let worker_cancel = cancel.clone();
+        std::thread::spawn(move || {
+            let interval = Duration::from_nanos(every_ns as u64);
+            loop {
+                std::thread::sleep(interval);
+                if worker_cancel.load(Ordering::Relaxed) {
+                    break;
+                }
+                match stor_connection(Span::unknown()) {
+                    Ok(conn) => {
+                        worker_runs.fetch_add(1, Ordering::Relaxed);
+                        if conn.execute_batch(&worker_sql).is_err() {
+                            worker_failures.fetch_add(1, Ordering::Relaxed);
+                        }
+                    }
+                    Err(_) => {
+                        worker_failures.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            }
+        });
+
+        schedules.insert(
+            name,
+            Schedule {
+                sql,
+                every_ns,
+                runs,
+                failures,
+                cancel,
+            },
+        );
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[derive(Clone)]
+pub struct StorScheduleList;
+
+impl Command for StorScheduleList {
+    fn name(&self) -> &str {
+        "stor schedule list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "List the active SQL schedules."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "List all schedules with their run counters",
+            example: "stor schedule list",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "schedule"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let schedules = lock_schedules(span)?;
+
+        let mut rows: Vec<Value> = schedules
+            .iter()
+            .map(|(name, schedule)| {
+                Value::record(
+                    record! {
+                        "name" => Value::string(name.clone(), span),
+                        "sql" => Value::string(schedule.sql.clone(), span),
+                        "every" => Value::duration(schedule.every_ns, span),
+                        "runs" => Value::int(schedule.runs.load(Ordering::Relaxed) as i64, span),
+                        "failures" => Value::int(schedule.failures.load(Ordering::Relaxed) as i64, span),
+                    },
+                    span,
+                )
+            })
+            .collect();
+        rows.sort_by_key(|row| {
+            row.get_data_by_key("name")
+                .and_then(|v| v.as_string().ok())
+                .unwrap_or_default()
+        });
+
+        Ok(Value::list(rows, span).into_pipeline_data())
+    }
+}
+
+#[derive(Clone)]
+pub struct StorScheduleRemove;
+
+impl Command for StorScheduleRemove {
+    fn name(&self) -> &str {
+        "stor schedule remove"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("name", SyntaxShape::String, "name of the schedule to stop")
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Stop and remove a SQL schedule."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Stop the refresh_totals schedule",
+            example: "stor schedule remove refresh_totals",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "schedule"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+
+        let mut schedules = lock_schedules(span)?;
+        match schedules.remove(&name) {
+            Some(schedule) => {
+                schedule.cancel.store(true, Ordering::Relaxed);
+                Ok(PipelineData::empty())
+            }
+            None => Err(ShellError::GenericError(
+                format!("No schedule named {name}"),
+                "nothing to remove".into(),
+                Some(span),
+                None,
+                Vec::new(),
+            )),
+        }
+    }
+}
+
+fn lock_schedules(
+    span: Span,
+) -> Result<std::sync::MutexGuard<'static, HashMap<String, Schedule>>, ShellError> {
+    SCHEDULES.lock().map_err(|e| {
+        ShellError::GenericError(
+            "Failed to lock the schedule registry".into(),
+            e.to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorScheduleAdd {});
+        test_examples(StorScheduleList {});
+        test_examples(StorScheduleRemove {});
+    }
+}
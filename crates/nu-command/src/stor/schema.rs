@@ -0,0 +1,134 @@
+use super::db::{run_stor_query, stor_connection};
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    record, Category, Example, IntoPipelineData, PipelineData, Record, ShellError, Signature,
+    Span, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct StorSchema;
+
+impl Command for StorSchema {
+    fn name(&self) -> &str {
+        "stor schema"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Record(vec![]))])
+            .allow_variants_without_examples(true)
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Show the full schema of the in-memory database."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Returns one record per table with its columns (name, type, nullable),
+constraints and indexes, gathered from DuckDB's introspection functions."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Inspect the columns of one table",
+            example: "stor schema | get logs.columns",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "schema", "columns", "constraints", "introspection"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let conn = stor_connection(span)?;
+
+        let tables: Vec<String> = conn
+            .prepare("SELECT table_name FROM duckdb_tables() ORDER BY table_name")
+            .and_then(|mut stmt| {
+                stmt.query_map([], |row| row.get(0))
+                    .and_then(|rows| rows.collect())
+            })
+            .map_err(|e| {
+                ShellError::GenericError(
+                    "Failed to list tables".into(),
+                    e.to_string(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                )
+            })?;
+
+        let mut schema = Record::new();
+        for table in tables {
+            schema.push(table.clone(), table_schema(&conn, &table, span)?);
+        }
+
+        Ok(Value::record(schema, span).into_pipeline_data())
+    }
+}
+
+fn table_schema(
+    conn: &duckdb::Connection,
+    table: &str,
+    span: Span,
+) -> Result<Value, ShellError> {
+    let escaped = table.replace('\'', "''");
+
+    let columns = run_stor_query(
+        conn,
+        &format!(
+            "SELECT column_name AS name, data_type AS type, is_nullable AS nullable
+             FROM duckdb_columns() WHERE table_name = '{escaped}' ORDER BY column_index"
+        ),
+        span,
+    )?;
+
+    let constraints = run_stor_query(
+        conn,
+        &format!(
+            "SELECT constraint_type AS type, constraint_text AS definition
+             FROM duckdb_constraints() WHERE table_name = '{escaped}'"
+        ),
+        span,
+    )?;
+
+    let indexes = run_stor_query(
+        conn,
+        &format!(
+            "SELECT index_name AS name, is_unique AS \"unique\", sql AS definition
+             FROM duckdb_indexes() WHERE table_name = '{escaped}'"
+        ),
+        span,
+    )?;
+
+    Ok(Value::record(
+        record! {
+            "columns" => columns,
+            "constraints" => constraints,
+            "indexes" => indexes,
+        },
+        span,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorSchema {});
+    }
+}
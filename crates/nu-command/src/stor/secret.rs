@@ -0,0 +1,313 @@
+use super::cached::validated_cache_name;
+use super::cloud::{load_extension, sql_escape};
+use super::db::{run_stor_execute, run_stor_execute_redacted, run_stor_query, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct StorSecretCreate;
+
+impl Command for StorSecretCreate {
+    fn name(&self) -> &str {
+        "stor secret create"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("name", SyntaxShape::String, "name for the secret")
+            .required_named(
+                "type",
+                SyntaxShape::String,
+                "secret type: s3, gcs, r2, azure, or http",
+                Some('t'),
+            )
+            .named(
+                "options",
+                SyntaxShape::Record(vec![]),
+                "type-specific fields, e.g. {key_id: ..., secret: ..., region: ...}",
+                Some('o'),
+            )
+            .named(
+                "scope",
+                SyntaxShape::String,
+                "path prefix the secret applies to, e.g. s3://my-bucket/",
+                Some('s'),
+            )
+            .switch(
+                "persistent",
+                "persist the secret on disk across sessions",
+                Some('p'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Create a DuckDB secret for remote storage access."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The unified surface under `stor cloud-init`: one secret type per
+storage provider, optionally scoped to a bucket or container prefix so
+different credentials can serve different buckets at once. Creating a
+secret with an existing name replaces it."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "One secret per bucket, scoped",
+                example: r#"stor secret create prod --type s3 --options {key_id: $env.KEY, secret: $env.SECRET} --scope "s3://prod-data/""#,
+                result: None,
+            },
+            Example {
+                description: "A persistent secret that survives the session",
+                example: "stor secret create lab --type s3 --options {provider: credential_chain} --persistent",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "secret", "credentials", "scope"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        let name = validated_cache_name(&name, span)?;
+        let type_name: String = call
+            .get_flag(engine_state, stack, "type")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "type".into(),
+                span,
+            })?;
+        let options: Option<Value> = call.get_flag(engine_state, stack, "options")?;
+        let scope: Option<String> = call.get_flag(engine_state, stack, "scope")?;
+        let persistent = call.has_flag("persistent");
+
+        let type_name = match type_name.as_str() {
+            "s3" | "gcs" | "r2" | "azure" | "http" => type_name.to_uppercase(),
+            other => {
+                return Err(ShellError::GenericError(
+                    format!("Unknown secret type {other}"),
+                    "expected s3, gcs, r2, azure, or http".into(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                ))
+            }
+        };
+
+        let conn = stor_connection(span)?;
+        let extension = if type_name == "AZURE" { "azure" } else { "httpfs" };
+        load_extension(&conn, extension, span)?;
+
+        let mut fields = vec![format!("TYPE {type_name}")];
+        if let Some(options) = &options {
+            for (field, value) in options.as_record()?.iter() {
+                fields.push(secret_field(field, value)?);
+            }
+        }
+        if let Some(scope) = &scope {
+            fields.push(format!("SCOPE '{}'", sql_escape(scope)));
+        }
+
+        let persistence = if persistent { "PERSISTENT " } else { "" };
+        // history and hooks see the statement shape only, never the values
+        run_stor_execute_redacted(
+            &conn,
+            &format!(
+                "CREATE OR REPLACE {persistence}SECRET {name} ({})",
+                fields.join(", ")
+            ),
+            &format!("CREATE OR REPLACE {persistence}SECRET {name} (TYPE {type_name}, [redacted])"),
+            span,
+        )?;
+
+        Ok(PipelineData::empty())
+    }
+}
+
+// One `FIELD value` entry of a CREATE SECRET statement. Bools and ints pass
+// through bare, provider/chain keywords stay unquoted, everything else is a
+// quoted string.
+fn secret_field(field: &str, value: &Value) -> Result<String, ShellError> {
+    let field = field.to_uppercase();
+    if !field.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(ShellError::GenericError(
+            format!("Invalid secret field {field}"),
+            "field names must be plain identifiers".into(),
+            Some(value.span()),
+            None,
+            Vec::new(),
+        ));
+    }
+    let rendered = match value {
+        Value::Bool { val, .. } => val.to_string(),
+        Value::Int { val, .. } => val.to_string(),
+        other => {
+            let text = other.as_string()?;
+            if field == "PROVIDER" || field == "CHAIN" || field == "URL_STYLE" {
+                if !text
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ';')
+                {
+                    return Err(ShellError::GenericError(
+                        format!("Invalid {field} value {text}"),
+                        "expected a bare keyword".into(),
+                        Some(value.span()),
+                        None,
+                        Vec::new(),
+                    ));
+                }
+                if field == "CHAIN" {
+                    format!("'{text}'")
+                } else {
+                    text
+                }
+            } else {
+                format!("'{}'", sql_escape(&text))
+            }
+        }
+    };
+    Ok(format!("{field} {rendered}"))
+}
+
+#[derive(Clone)]
+pub struct StorSecretList;
+
+impl Command for StorSecretList {
+    fn name(&self) -> &str {
+        "stor secret list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "List the registered secrets, with their values redacted."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "See which credentials are registered and their scopes",
+            example: "stor secret list",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "secret", "list", "credentials"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let conn = stor_connection(span)?;
+
+        // duckdb_secrets() redacts sensitive fields by default.
+        run_stor_query(
+            &conn,
+            "SELECT name, type, provider, persistent, scope, secret_string \
+             FROM duckdb_secrets() ORDER BY name",
+            span,
+        )
+        .map(IntoPipelineData::into_pipeline_data)
+    }
+}
+
+#[derive(Clone)]
+pub struct StorSecretDrop;
+
+impl Command for StorSecretDrop {
+    fn name(&self) -> &str {
+        "stor secret drop"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("name", SyntaxShape::String, "secret to drop")
+            .switch(
+                "persistent",
+                "drop a persistent secret from disk",
+                Some('p'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Drop a registered secret."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Forget a session credential",
+            example: "stor secret drop prod",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "secret", "drop", "forget"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        let name = validated_cache_name(&name, span)?;
+        let persistence = if call.has_flag("persistent") {
+            "PERSISTENT "
+        } else {
+            ""
+        };
+
+        let conn = stor_connection(span)?;
+        run_stor_execute(&conn, &format!("DROP {persistence}SECRET {name}"), span)?;
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorSecretCreate {});
+        test_examples(StorSecretList {});
+        test_examples(StorSecretDrop {});
+    }
+}
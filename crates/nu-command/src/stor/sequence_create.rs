@@ -0,0 +1,87 @@
+use super::db::{quote_ident, run_stor_execute, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct StorSequenceCreate;
+
+impl Command for StorSequenceCreate {
+    fn name(&self) -> &str {
+        "stor sequence create"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("name", SyntaxShape::String, "name of the sequence to create")
+            .named("start", SyntaxShape::Int, "first value of the sequence", Some('s'))
+            .named("increment", SyntaxShape::Int, "step between values", Some('i'))
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Create a sequence in the in-memory database."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Create a sequence starting at 1",
+                example: "stor sequence create ids",
+                result: None,
+            },
+            Example {
+                description: "Create a sequence counting down from 100",
+                example: "stor sequence create countdown --start 100 --increment -1",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "sequence", "id"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        let start: Option<i64> = call.get_flag(engine_state, stack, "start")?;
+        let increment: Option<i64> = call.get_flag(engine_state, stack, "increment")?;
+
+        let mut sql = format!("CREATE SEQUENCE {}", quote_ident(&name));
+        if let Some(start) = start {
+            sql.push_str(&format!(" START {start}"));
+        }
+        if let Some(increment) = increment {
+            sql.push_str(&format!(" INCREMENT {increment}"));
+        }
+
+        let conn = stor_connection(span)?;
+        run_stor_execute(&conn, &sql, span)?;
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorSequenceCreate {});
+    }
+}
@@ -0,0 +1,79 @@
+use super::db::stor_connection;
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct StorSequenceNext;
+
+impl Command for StorSequenceNext {
+    fn name(&self) -> &str {
+        "stor sequence next"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Int)])
+            .allow_variants_without_examples(true)
+            .required("name", SyntaxShape::String, "name of the sequence to advance")
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Get the next value of a sequence as an int."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Take the next id from the ids sequence",
+            example: "stor sequence next ids",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "sequence", "nextval", "id"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+
+        let conn = stor_connection(span)?;
+        let next: i64 = conn
+            .query_row("SELECT nextval(?)", [&name], |row| row.get(0))
+            .map_err(|e| {
+                ShellError::GenericError(
+                    "Failed to advance sequence".into(),
+                    e.to_string(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                )
+            })?;
+
+        Ok(Value::int(next, span).into_pipeline_data())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorSequenceNext {});
+    }
+}
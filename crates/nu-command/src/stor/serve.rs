@@ -0,0 +1,322 @@
+use super::db::{run_stor_query, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, Record, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+use once_cell::sync::Lazy;
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+};
+
+// The one active HTTP server, if any: its port and the flag its accept loop
+// polls to shut down.
+static SERVER: Lazy<Mutex<Option<(u16, Arc<AtomicBool>)>>> = Lazy::new(|| Mutex::new(None));
+
+// Requests larger than this are rejected outright so a client cannot grow
+// the request buffer without bound.
+const MAX_REQUEST_BYTES: usize = 1024 * 1024;
+
+#[derive(Clone)]
+pub struct StorServe;
+
+impl Command for StorServe {
+    fn name(&self) -> &str {
+        "stor serve"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+            .allow_variants_without_examples(true)
+            .named("port", SyntaxShape::Int, "port to listen on (default 8399)", Some('p'))
+            .switch("stop", "stop the running server", Some('s'))
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Expose the in-memory database over HTTP."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "POST a SQL query to / and the matching rows come back as JSON. A
+bearer token is generated at startup and every request must present it:
+    curl -H 'Authorization: Bearer <token>' -d 'SELECT 42 AS answer' http://127.0.0.1:8399/
+Binding 127.0.0.1 alone is not enough: without the token any local process
+(or a web page firing a cross-origin POST at localhost) could run arbitrary
+SQL, which includes reading and writing local files. The server runs until
+stopped with --stop."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Serve the database on the default port",
+                example: "stor serve",
+                result: None,
+            },
+            Example {
+                description: "Stop the server",
+                example: "stor serve --stop",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "http", "serve", "api"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        if call.has_flag("stop") {
+            return stop_server(span);
+        }
+
+        let port: u16 = call
+            .get_flag::<i64>(engine_state, stack, "port")?
+            .unwrap_or(8399) as u16;
+
+        let mut server = lock_server(span)?;
+        if let Some((active_port, _)) = server.as_ref() {
+            return Err(ShellError::GenericError(
+                format!("A server is already running on port {active_port}"),
+                "only one server can run at a time".into(),
+                Some(span),
+                Some("stop it first with `stor serve --stop`".into()),
+                Vec::new(),
+            ));
+        }
+
+        let listener = TcpListener::bind(("127.0.0.1", port)).map_err(|e| {
+            ShellError::GenericError(
+                format!("Failed to bind 127.0.0.1:{port}"),
+                e.to_string(),
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+        // Without a token, anything able to reach 127.0.0.1 — including a web
+        // page firing a simple cross-origin POST — could run arbitrary SQL.
+        let token: String = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(32)
+            .map(char::from)
+            .collect();
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let worker_stop = stop.clone();
+        let worker_token = token.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                if worker_stop.load(Ordering::Relaxed) {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    // one request per connection keeps this trivially simple
+                    let _ = handle_request(stream, &worker_token);
+                }
+            }
+        });
+
+        *server = Some((port, stop));
+
+        Ok(Value::string(
+            format!(
+                "serving the stor database on http://127.0.0.1:{port}\n\
+                 requests must send: Authorization: Bearer {token}"
+            ),
+            span,
+        )
+        .into_pipeline_data())
+    }
+}
+
+fn stop_server(span: Span) -> Result<PipelineData, ShellError> {
+    let mut server = lock_server(span)?;
+    match server.take() {
+        Some((port, stop)) => {
+            stop.store(true, Ordering::Relaxed);
+            // poke the accept loop so it notices the flag
+            let _ = TcpStream::connect(("127.0.0.1", port));
+            Ok(Value::string("server stopped", span).into_pipeline_data())
+        }
+        None => Err(ShellError::GenericError(
+            "No server is running".into(),
+            "nothing to stop".into(),
+            Some(span),
+            None,
+            Vec::new(),
+        )),
+    }
+}
+
+fn lock_server(
+    span: Span,
+) -> Result<std::sync::MutexGuard<'static, Option<(u16, Arc<AtomicBool>)>>, ShellError> {
+    SERVER.lock().map_err(|e| {
+        ShellError::GenericError(
+            "Failed to lock the server state".into(),
+            e.to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    })
+}
+
+fn handle_request(mut stream: TcpStream, token: &str) -> std::io::Result<()> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    // read until the headers are complete, then until content-length is met;
+    // requests over MAX_REQUEST_BYTES are cut off and rejected
+    let mut too_large = false;
+    loop {
+        let n = stream.read(&mut chunk)?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if buf.len() > MAX_REQUEST_BYTES {
+            too_large = true;
+            break;
+        }
+        if let Some(header_end) = find_header_end(&buf) {
+            let content_length = header_value(&buf[..header_end], "content-length")
+                .and_then(|v| v.parse::<usize>().ok())
+                .unwrap_or(0);
+            if content_length > MAX_REQUEST_BYTES {
+                too_large = true;
+                break;
+            }
+            if buf.len() >= header_end + 4 + content_length {
+                break;
+            }
+        }
+    }
+
+    if too_large {
+        return respond(
+            &mut stream,
+            "413 Payload Too Large",
+            serde_json::json!({ "error": "request too large" }).to_string(),
+        );
+    }
+
+    let header_end = find_header_end(&buf);
+
+    let authorized = header_end
+        .and_then(|end| header_value(&buf[..end], "authorization"))
+        .map(|value| match value.split_once(' ') {
+            Some((scheme, presented)) => {
+                scheme.eq_ignore_ascii_case("bearer") && presented.trim() == token
+            }
+            None => false,
+        })
+        .unwrap_or(false);
+    if !authorized {
+        return respond(
+            &mut stream,
+            "401 Unauthorized",
+            serde_json::json!({ "error": "missing or invalid bearer token" }).to_string(),
+        );
+    }
+
+    let body = match header_end {
+        Some(header_end) => String::from_utf8_lossy(&buf[header_end + 4..]).to_string(),
+        None => String::new(),
+    };
+
+    let span = Span::unknown();
+    let response = stor_connection(span)
+        .and_then(|conn| run_stor_query(&conn, body.trim(), span))
+        .map(|value| value_to_json(&value).to_string());
+
+    let (status, payload) = match response {
+        Ok(json) => ("200 OK", json),
+        Err(err) => (
+            "400 Bad Request",
+            serde_json::json!({ "error": err.to_string() }).to_string(),
+        ),
+    };
+
+    respond(&mut stream, status, payload)
+}
+
+fn respond(stream: &mut TcpStream, status: &str, payload: String) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{payload}",
+        payload.len()
+    )
+}
+
+// The value of the named header, matched case-insensitively; the token must
+// compare against the header's original casing, so only names are lowered.
+fn header_value(headers: &[u8], name: &str) -> Option<String> {
+    String::from_utf8_lossy(headers).lines().find_map(|line| {
+        let (header, value) = line.split_once(':')?;
+        if header.trim().eq_ignore_ascii_case(name) {
+            Some(value.trim().to_string())
+        } else {
+            None
+        }
+    })
+}
+
+fn find_header_end(buf: &[u8]) -> Option<usize> {
+    buf.windows(4).position(|w| w == b"\r\n\r\n")
+}
+
+fn value_to_json(value: &Value) -> serde_json::Value {
+    match value {
+        Value::Bool { val, .. } => serde_json::Value::Bool(*val),
+        Value::Int { val, .. } => serde_json::Value::Number((*val).into()),
+        Value::Float { val, .. } => serde_json::Number::from_f64(*val)
+            .map(serde_json::Value::Number)
+            .unwrap_or(serde_json::Value::Null),
+        Value::List { vals, .. } => {
+            serde_json::Value::Array(vals.iter().map(value_to_json).collect())
+        }
+        Value::Record { val, .. } => serde_json::Value::Object(
+            record_to_json(val)
+        ),
+        Value::Nothing { .. } => serde_json::Value::Null,
+        other => serde_json::Value::String(other.as_string().unwrap_or_default()),
+    }
+}
+
+fn record_to_json(record: &Record) -> serde_json::Map<String, serde_json::Value> {
+    record
+        .iter()
+        .map(|(k, v)| (k.clone(), value_to_json(v)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorServe {});
+    }
+}
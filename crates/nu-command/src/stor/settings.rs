@@ -0,0 +1,175 @@
+use super::db::{record_startup_sql, run_stor_execute, run_stor_query, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct StorSet;
+
+impl Command for StorSet {
+    fn name(&self) -> &str {
+        "stor set"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("setting", SyntaxShape::String, "name of the DuckDB setting")
+            .required("value", SyntaxShape::Any, "value to set it to")
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Change a DuckDB session setting."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Wraps `SET <setting> = <value>` for tuning the engine from the shell,
+e.g. threads, memory_limit, or TimeZone. Settings are reapplied if the
+database is later reset."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Cap DuckDB's memory use",
+                example: "stor set memory_limit '2GB'",
+                result: None,
+            },
+            Example {
+                description: "Use four threads for queries",
+                example: "stor set threads 4",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "set", "settings", "configure", "tune"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let setting: String = call.req(engine_state, stack, 0)?;
+        let value: Value = call.req(engine_state, stack, 1)?;
+
+        if !setting
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+            || setting.is_empty()
+        {
+            return Err(ShellError::GenericError(
+                format!("Invalid setting name {setting}"),
+                "setting names are alphanumeric with underscores".into(),
+                Some(span),
+                None,
+                Vec::new(),
+            ));
+        }
+
+        let literal = match &value {
+            Value::Int { val, .. } => val.to_string(),
+            Value::Float { val, .. } => val.to_string(),
+            Value::Bool { val, .. } => val.to_string(),
+            other => format!("'{}'", other.as_string()?.replace('\'', "''")),
+        };
+
+        let sql = format!("SET {setting} = {literal}");
+        let conn = stor_connection(span)?;
+        run_stor_execute(&conn, &sql, span)?;
+        record_startup_sql(&format!("set {setting}"), &sql);
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[derive(Clone)]
+pub struct StorSettings;
+
+impl Command for StorSettings {
+    fn name(&self) -> &str {
+        "stor settings"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .rest(
+                "names",
+                SyntaxShape::String,
+                "only show settings with these names",
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Show the current DuckDB configuration."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Check the current memory limit",
+            example: "stor settings memory_limit",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "settings", "configuration", "show"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let names: Vec<String> = call.rest(engine_state, stack, 0)?;
+
+        let filter = if names.is_empty() {
+            String::new()
+        } else {
+            let list = names
+                .iter()
+                .map(|name| format!("'{}'", name.replace('\'', "''")))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(" WHERE name IN ({list})")
+        };
+
+        let conn = stor_connection(span)?;
+        run_stor_query(
+            &conn,
+            &format!("SELECT name, value, description FROM duckdb_settings(){filter} ORDER BY name"),
+            span,
+        )
+        .map(IntoPipelineData::into_pipeline_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorSet {});
+        test_examples(StorSettings {});
+    }
+}
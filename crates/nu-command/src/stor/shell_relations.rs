@@ -0,0 +1,378 @@
+use duckdb::core::{DataChunkHandle, Inserter, LogicalTypeHandle, LogicalTypeId};
+use duckdb::vtab::{BindInfo, Free, FunctionInfo, InitInfo, VTab};
+use duckdb::Connection;
+use nu_engine::scope::ScopeData;
+use nu_protocol::engine::{EngineState, Stack};
+use nu_protocol::Span;
+use once_cell::sync::Lazy;
+use std::ffi::{c_char, CStr, CString};
+use std::sync::Mutex;
+use std::time::Duration;
+
+// Table functions exposing shell data to SQL. They are registered on every
+// fresh in-memory connection, so `SELECT * FROM nu_ls('.')` and
+// `SELECT * FROM nu_ps()` work out of the box and always see live data.
+pub(super) fn register_shell_relations(conn: &Connection) -> duckdb::Result<()> {
+    conn.register_table_function::<LsVTab>("nu_ls")?;
+    conn.register_table_function::<PsVTab>("nu_ps")?;
+    conn.register_table_function::<EnvVTab>("nu_env")?;
+    conn.register_table_function::<VarsVTab>("nu_vars")?;
+    Ok(())
+}
+
+// Snapshots of the shell's environment and variables, refreshed by the stor
+// commands right before they hand a query to DuckDB. The table functions read
+// from these because DuckDB calls them from its own threads, where no
+// EngineState or Stack is available.
+static SHELL_ENV: Lazy<Mutex<Vec<(String, String)>>> = Lazy::new(|| Mutex::new(Vec::new()));
+static SHELL_VARS: Lazy<Mutex<Vec<(String, String)>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// Snapshot the shell's environment variables and scope variables so the
+/// `nu_env()` and `nu_vars()` relations see current values.
+pub fn refresh_shell_state(engine_state: &EngineState, stack: &Stack) {
+    let span = Span::unknown();
+
+    if let Ok(mut env) = SHELL_ENV.lock() {
+        env.clear();
+        for (name, value) in stack.get_env_vars(engine_state) {
+            env.push((name, value.as_string().unwrap_or_default()));
+        }
+    }
+
+    if let Ok(mut vars) = SHELL_VARS.lock() {
+        vars.clear();
+        let mut scope_data = ScopeData::new(engine_state, stack);
+        scope_data.populate_vars();
+        for var in scope_data.collect_vars(span) {
+            let name = var
+                .get_data_by_key("name")
+                .and_then(|v| v.as_string().ok())
+                .unwrap_or_default();
+            let value = var
+                .get_data_by_key("value")
+                .and_then(|v| v.as_string().ok())
+                .unwrap_or_default();
+            vars.push((name, value));
+        }
+    }
+}
+
+fn snapshot_env() -> Vec<(String, String)> {
+    match SHELL_ENV.lock() {
+        Ok(env) if !env.is_empty() => env.clone(),
+        // before the first refresh, fall back to the process environment
+        _ => std::env::vars().collect(),
+    }
+}
+
+fn snapshot_vars() -> Vec<(String, String)> {
+    SHELL_VARS.lock().map(|v| v.clone()).unwrap_or_default()
+}
+
+// A DuckDB result chunk holds at most this many values per vector, so a table
+// function must never write more rows than this into one chunk: each `func`
+// call emits one batch and resumes from a cursor kept in the init data.
+const STANDARD_VECTOR_SIZE: usize = 2048;
+
+// Init data shared by all the shell relations: the full row set, computed on
+// the first `func` call, plus the cursor the next batch resumes from.
+#[repr(C)]
+pub(super) struct BatchInitData<T> {
+    cursor: usize,
+    rows: *mut Vec<T>,
+}
+
+impl<T> Free for BatchInitData<T> {
+    fn free(&mut self) {
+        unsafe {
+            if !self.rows.is_null() {
+                drop(Box::from_raw(self.rows));
+            }
+        }
+    }
+}
+
+unsafe fn init_batch<T>(data: *mut BatchInitData<T>) {
+    (*data).cursor = 0;
+    (*data).rows = std::ptr::null_mut();
+}
+
+/// The next chunk's worth of rows, at most [`STANDARD_VECTOR_SIZE`] of them;
+/// the full row set is computed on the first call. An empty batch tells
+/// DuckDB the scan is finished.
+unsafe fn next_batch<'a, T>(
+    data: *mut BatchInitData<T>,
+    compute: impl FnOnce() -> Result<Vec<T>, Box<dyn std::error::Error>>,
+) -> Result<&'a [T], Box<dyn std::error::Error>> {
+    if (*data).rows.is_null() {
+        (*data).rows = Box::into_raw(Box::new(compute()?));
+    }
+    let rows = &*(*data).rows;
+    let start = (*data).cursor.min(rows.len());
+    let len = (rows.len() - start).min(STANDARD_VECTOR_SIZE);
+    (*data).cursor = start + len;
+    Ok(&rows[start..start + len])
+}
+
+#[repr(C)]
+pub(super) struct LsBindData {
+    path: *mut c_char,
+}
+
+impl Free for LsBindData {
+    fn free(&mut self) {
+        unsafe {
+            if !self.path.is_null() {
+                drop(CString::from_raw(self.path));
+            }
+        }
+    }
+}
+
+type LsRow = (String, &'static str, i64);
+
+pub(super) struct LsVTab;
+
+impl VTab for LsVTab {
+    type InitData = BatchInitData<LsRow>;
+    type BindData = LsBindData;
+
+    unsafe fn bind(bind: &BindInfo, data: *mut LsBindData) -> Result<(), Box<dyn std::error::Error>> {
+        bind.add_result_column("name", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("type", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("size", LogicalTypeHandle::from(LogicalTypeId::Bigint));
+
+        let path = if bind.get_parameter_count() > 0 {
+            bind.get_parameter(0).to_string()
+        } else {
+            ".".to_string()
+        };
+        (*data).path = CString::new(path)?.into_raw();
+        Ok(())
+    }
+
+    unsafe fn init(
+        _: &InitInfo,
+        data: *mut Self::InitData,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        init_batch(data);
+        Ok(())
+    }
+
+    unsafe fn func(
+        func: &FunctionInfo,
+        output: &mut DataChunkHandle,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data::<Self::InitData>();
+        let bind_data = func.get_bind_data::<LsBindData>();
+        let path = CStr::from_ptr((*bind_data).path).to_str()?.to_string();
+
+        let batch = next_batch(init_data, || {
+            let mut rows = Vec::new();
+            for entry in std::fs::read_dir(path)? {
+                let entry = entry?;
+                // the entry's file type does not follow symlinks, so links
+                // classify as "symlink" instead of as whatever they point at
+                let file_type = entry.file_type()?;
+                rows.push((
+                    entry.file_name().to_string_lossy().into_owned(),
+                    if file_type.is_dir() {
+                        "dir"
+                    } else if file_type.is_symlink() {
+                        "symlink"
+                    } else {
+                        "file"
+                    },
+                    entry.metadata()?.len() as i64,
+                ));
+            }
+            Ok(rows)
+        })?;
+
+        let mut names = output.flat_vector(0);
+        let mut types = output.flat_vector(1);
+        let mut sizes = output.flat_vector(2);
+
+        for (row, (name, file_type, size)) in batch.iter().enumerate() {
+            names.insert(row, name.as_str());
+            types.insert(row, *file_type);
+            sizes.as_mut_slice::<i64>()[row] = *size;
+        }
+
+        output.set_len(batch.len());
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        Some(vec![LogicalTypeHandle::from(LogicalTypeId::Varchar)])
+    }
+}
+
+#[repr(C)]
+pub(super) struct PsBindData {}
+
+impl Free for PsBindData {}
+
+type PsRow = (i64, i64, String, f64, i64, i64);
+
+pub(super) struct PsVTab;
+
+impl VTab for PsVTab {
+    type InitData = BatchInitData<PsRow>;
+    type BindData = PsBindData;
+
+    unsafe fn bind(bind: &BindInfo, _: *mut PsBindData) -> Result<(), Box<dyn std::error::Error>> {
+        bind.add_result_column("pid", LogicalTypeHandle::from(LogicalTypeId::Bigint));
+        bind.add_result_column("ppid", LogicalTypeHandle::from(LogicalTypeId::Bigint));
+        bind.add_result_column("name", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("cpu", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("mem", LogicalTypeHandle::from(LogicalTypeId::Bigint));
+        bind.add_result_column("virtual", LogicalTypeHandle::from(LogicalTypeId::Bigint));
+        Ok(())
+    }
+
+    unsafe fn init(
+        _: &InitInfo,
+        data: *mut Self::InitData,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        init_batch(data);
+        Ok(())
+    }
+
+    unsafe fn func(
+        func: &FunctionInfo,
+        output: &mut DataChunkHandle,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data::<Self::InitData>();
+
+        let batch = next_batch(init_data, || {
+            Ok(nu_system::collect_proc(Duration::from_millis(100), false)
+                .iter()
+                .map(|proc| {
+                    (
+                        proc.pid() as i64,
+                        proc.ppid() as i64,
+                        proc.name(),
+                        proc.cpu_usage(),
+                        proc.mem_size() as i64,
+                        proc.virtual_size() as i64,
+                    )
+                })
+                .collect())
+        })?;
+
+        let mut pids = output.flat_vector(0);
+        let mut ppids = output.flat_vector(1);
+        let mut names = output.flat_vector(2);
+        let mut cpus = output.flat_vector(3);
+        let mut mems = output.flat_vector(4);
+        let mut virtuals = output.flat_vector(5);
+
+        for (row, (pid, ppid, name, cpu, mem, virtual_size)) in batch.iter().enumerate() {
+            pids.as_mut_slice::<i64>()[row] = *pid;
+            ppids.as_mut_slice::<i64>()[row] = *ppid;
+            names.insert(row, name.as_str());
+            cpus.as_mut_slice::<f64>()[row] = *cpu;
+            mems.as_mut_slice::<i64>()[row] = *mem;
+            virtuals.as_mut_slice::<i64>()[row] = *virtual_size;
+        }
+
+        output.set_len(batch.len());
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+/// Emit a snapshot of (name, value) pairs as two-column result chunks, one
+/// batch per call.
+unsafe fn emit_pairs(
+    func: &FunctionInfo,
+    output: &mut DataChunkHandle,
+    snapshot: impl FnOnce() -> Vec<(String, String)>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let init_data = func.get_init_data::<BatchInitData<(String, String)>>();
+    let batch = next_batch(init_data, || Ok(snapshot()))?;
+
+    let mut names = output.flat_vector(0);
+    let mut values = output.flat_vector(1);
+
+    for (row, (name, value)) in batch.iter().enumerate() {
+        names.insert(row, name.as_str());
+        values.insert(row, value.as_str());
+    }
+
+    output.set_len(batch.len());
+    Ok(())
+}
+
+#[repr(C)]
+pub(super) struct EmptyBindData {}
+
+impl Free for EmptyBindData {}
+
+pub(super) struct EnvVTab;
+
+impl VTab for EnvVTab {
+    type InitData = BatchInitData<(String, String)>;
+    type BindData = EmptyBindData;
+
+    unsafe fn bind(bind: &BindInfo, _: *mut EmptyBindData) -> Result<(), Box<dyn std::error::Error>> {
+        bind.add_result_column("name", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("value", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        Ok(())
+    }
+
+    unsafe fn init(
+        _: &InitInfo,
+        data: *mut Self::InitData,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        init_batch(data);
+        Ok(())
+    }
+
+    unsafe fn func(
+        func: &FunctionInfo,
+        output: &mut DataChunkHandle,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        emit_pairs(func, output, snapshot_env)
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+pub(super) struct VarsVTab;
+
+impl VTab for VarsVTab {
+    type InitData = BatchInitData<(String, String)>;
+    type BindData = EmptyBindData;
+
+    unsafe fn bind(bind: &BindInfo, _: *mut EmptyBindData) -> Result<(), Box<dyn std::error::Error>> {
+        bind.add_result_column("name", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("value", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        Ok(())
+    }
+
+    unsafe fn init(
+        _: &InitInfo,
+        data: *mut Self::InitData,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        init_batch(data);
+        Ok(())
+    }
+
+    unsafe fn func(
+        func: &FunctionInfo,
+        output: &mut DataChunkHandle,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        emit_pairs(func, output, snapshot_vars)
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
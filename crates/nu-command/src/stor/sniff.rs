@@ -0,0 +1,90 @@
+use super::db::{run_stor_query, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Spanned,
+    SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct StorSniff;
+
+impl Command for StorSniff {
+    fn name(&self) -> &str {
+        "stor sniff"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .required("path", SyntaxShape::Filepath, "CSV file to sniff")
+            .named(
+                "sample-size",
+                SyntaxShape::Int,
+                "number of rows to sample while sniffing",
+                Some('s'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Detect the dialect and column types of a CSV file."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Wraps DuckDB's sniff_csv() and reports the detected delimiter, quoting,
+header presence, and per-column types, plus a ready-to-use read_csv prompt."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Sniff a CSV file of unknown provenance",
+            example: "stor sniff export.csv",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "csv", "sniff", "dialect", "delimiter"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let path: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let sample_size: Option<i64> = call.get_flag(engine_state, stack, "sample-size")?;
+
+        let sql = match sample_size {
+            Some(sample_size) => format!(
+                "SELECT * FROM sniff_csv('{}', sample_size = {sample_size})",
+                path.item.replace('\'', "''")
+            ),
+            None => format!(
+                "SELECT * FROM sniff_csv('{}')",
+                path.item.replace('\'', "''")
+            ),
+        };
+
+        let conn = stor_connection(span)?;
+        run_stor_query(&conn, &sql, span).map(IntoPipelineData::into_pipeline_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorSniff {});
+    }
+}
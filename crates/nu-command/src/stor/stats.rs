@@ -0,0 +1,93 @@
+use super::db::{run_stor_query, stor_connection};
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, Record, ShellError, Signature, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct StorStats;
+
+impl Command for StorStats {
+    fn name(&self) -> &str {
+        "stor stats"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Record(vec![]))])
+            .allow_variants_without_examples(true)
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Report size and usage statistics for the in-memory database."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Combines `pragma database_size` (total size, WAL size, block and
+memory usage) with per-table row and column counts from the catalog into
+one record, so a session's footprint is visible at a glance."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Check how much memory the store is using",
+                example: "stor stats | get memory_usage",
+                result: None,
+            },
+            Example {
+                description: "Find the biggest tables",
+                example: "stor stats | get tables | sort-by rows --reverse",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "stats", "size", "memory", "usage"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let conn = stor_connection(span)?;
+
+        let sizes = run_stor_query(&conn, "CALL pragma_database_size()", span)?;
+        let mut stats = match sizes {
+            Value::List { vals, .. } => match vals.into_iter().next() {
+                Some(Value::Record { val, .. }) => val,
+                _ => Record::new(),
+            },
+            _ => Record::new(),
+        };
+
+        let tables = run_stor_query(
+            &conn,
+            "SELECT table_name AS \"table\", estimated_size AS rows, column_count AS columns \
+             FROM duckdb_tables() WHERE NOT internal ORDER BY table_name",
+            span,
+        )?;
+        stats.push("tables", tables);
+
+        Ok(Value::record(stats, span).into_pipeline_data())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorStats {});
+    }
+}
@@ -0,0 +1,53 @@
+use nu_engine::get_full_help;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct Stor;
+
+impl Command for Stor {
+    fn name(&self) -> &str {
+        "stor"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("stor")
+            .category(Category::Custom("database".into()))
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+    }
+
+    fn usage(&self) -> &str {
+        "Various commands for working with the in-memory DuckDB database."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "You must use one of the following subcommands. Using this command as-is will only produce this help message."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "memory", "storage"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::string(
+            get_full_help(
+                &Stor.signature(),
+                &Stor.examples(),
+                engine_state,
+                stack,
+                false,
+            ),
+            call.head,
+        )
+        .into_pipeline_data())
+    }
+}
@@ -0,0 +1,96 @@
+use super::db::{quote_ident, register_ctrlc, run_stor_query, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct StorSummarize;
+
+impl Command for StorSummarize {
+    fn name(&self) -> &str {
+        "stor summarize"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .required(
+                "source",
+                SyntaxShape::String,
+                "table name or SELECT query to summarize",
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Summarize a table or query column by column."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Wraps DuckDB's SUMMARIZE, returning per-column type, min, max,
+approximate unique count, average, standard deviation, quartiles and null
+percentage."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Profile a whole table",
+                example: "stor summarize logs",
+                result: None,
+            },
+            Example {
+                description: "Profile a filtered subset",
+                example: r#"stor summarize "select * from logs where level = 'error'""#,
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "summarize", "statistics", "profile", "describe"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let source: String = call.req(engine_state, stack, 0)?;
+        register_ctrlc(&engine_state.ctrlc);
+
+        // a bare identifier is a table; anything else is treated as a query
+        let trimmed = source.trim();
+        let target = if trimmed
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+        {
+            quote_ident(trimmed)
+        } else {
+            format!("({trimmed})")
+        };
+
+        let conn = stor_connection(span)?;
+        run_stor_query(&conn, &format!("SUMMARIZE {target}"), span)
+            .map(IntoPipelineData::into_pipeline_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorSummarize {});
+    }
+}
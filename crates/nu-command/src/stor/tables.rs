@@ -0,0 +1,83 @@
+use super::db::{ensure_comments_table, run_stor_query, stor_connection};
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type,
+};
+
+#[derive(Clone)]
+pub struct StorTables;
+
+impl Command for StorTables {
+    fn name(&self) -> &str {
+        "stor tables"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "List the tables in the in-memory database with row counts and sizes."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Row counts come from DuckDB's table statistics. Any table comments set
+with `stor comment set` are shown alongside."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "See what's in the database",
+            example: "stor tables",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "tables", "list", "rows", "size"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let conn = stor_connection(span)?;
+        ensure_comments_table(&conn, span)?;
+
+        run_stor_query(
+            &conn,
+            "SELECT t.table_name AS name,
+                    t.schema_name AS schema,
+                    t.estimated_size AS rows,
+                    t.column_count AS columns,
+                    coalesce(c.comment, '') AS comment
+             FROM duckdb_tables() t
+             LEFT JOIN nu_stor_comments c
+               ON c.table_name = t.table_name AND c.column_name = ''
+             WHERE t.table_name NOT LIKE 'nu_stor_%'
+             ORDER BY t.table_name",
+            span,
+        )
+        .map(IntoPipelineData::into_pipeline_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorTables {});
+    }
+}
@@ -0,0 +1,110 @@
+use super::db::{ensure_table_for_record, insert_record, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct StorTee;
+
+impl Command for StorTee {
+    fn name(&self) -> &str {
+        "stor tee"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::List(Box::new(Type::Any)),
+                Type::List(Box::new(Type::Any)),
+            )])
+            .allow_variants_without_examples(true)
+            .required_named(
+                "table-name",
+                SyntaxShape::String,
+                "table to copy the rows into",
+                Some('t'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Pass pipeline rows through unchanged while copying them into a table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The table is created on the first row if it does not exist yet. Rows that
+are not records pass through without being stored."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Record a directory listing while still displaying it",
+            example: "ls | stor tee --table-name files",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "tee", "copy", "log"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let table_name: String = call
+            .get_flag(engine_state, stack, "table-name")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "table-name".into(),
+                span,
+            })?;
+        let ctrlc = engine_state.ctrlc.clone();
+
+        let mut columns: Option<Vec<String>> = None;
+
+        Ok(input
+            .into_iter()
+            .map(move |value| {
+                if let Value::Record { val: record, .. } = &value {
+                    let stored = stor_connection(span).and_then(|conn| {
+                        if columns.is_none() {
+                            columns = Some(ensure_table_for_record(
+                                &conn, &table_name, record, span,
+                            )?);
+                        }
+                        if let Some(columns) = &columns {
+                            insert_record(&conn, &table_name, columns, record, span)?;
+                        }
+                        Ok(())
+                    });
+
+                    if let Err(err) = stored {
+                        return Value::error(err, span);
+                    }
+                }
+
+                value
+            })
+            .into_pipeline_data(ctrlc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorTee {});
+    }
+}
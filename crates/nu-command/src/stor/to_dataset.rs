@@ -0,0 +1,155 @@
+use super::db::{quote_ident, run_stor_execute, stor_connection};
+use super::progress::StorProgress;
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Spanned,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct StorToDataset;
+
+impl Command for StorToDataset {
+    fn name(&self) -> &str {
+        "stor to-dataset"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::List(Box::new(Type::String)))])
+            .allow_variants_without_examples(true)
+            .required(
+                "path",
+                SyntaxShape::Filepath,
+                "directory to write the Parquet dataset into",
+            )
+            .named(
+                "tables",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "only export these tables; all tables when omitted",
+                Some('t'),
+            )
+            .named(
+                "partition-by",
+                SyntaxShape::String,
+                "write each table partitioned by this column",
+                Some('p'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Export the in-memory database as a directory of Parquet files."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Each table becomes <path>/<table>.parquet, or a hive-partitioned directory
+when --partition-by is given. Returns the list of written paths."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Export every table to ./dataset",
+            example: "stor to-dataset dataset",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "parquet", "export", "dataset"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let path: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let tables: Option<Vec<String>> = call.get_flag(engine_state, stack, "tables")?;
+        let partition_by: Option<String> = call.get_flag(engine_state, stack, "partition-by")?;
+
+        std::fs::create_dir_all(&path.item)
+            .map_err(|e| ShellError::CreateNotPossible(e.to_string(), path.span))?;
+
+        let conn = stor_connection(span)?;
+
+        let tables = match tables {
+            Some(tables) => tables,
+            None => {
+                let mut stmt = conn
+                    .prepare("SELECT table_name FROM duckdb_tables()")
+                    .map_err(|e| {
+                        ShellError::GenericError(
+                            "Failed to list tables".into(),
+                            e.to_string(),
+                            Some(span),
+                            None,
+                            Vec::new(),
+                        )
+                    })?;
+                stmt.query_map([], |row| row.get(0))
+                    .and_then(|rows| rows.collect::<Result<Vec<String>, _>>())
+                    .map_err(|e| {
+                        ShellError::GenericError(
+                            "Failed to list tables".into(),
+                            e.to_string(),
+                            Some(span),
+                            None,
+                            Vec::new(),
+                        )
+                    })?
+            }
+        };
+
+        let progress = StorProgress::new(Some(tables.len() as u64), "exporting tables");
+        let mut written = Vec::with_capacity(tables.len());
+        for table in tables {
+            progress.set_message(format!("exporting {table}"));
+            let target = match &partition_by {
+                Some(column) => {
+                    let dir = format!("{}/{}", path.item, table);
+                    let sql = format!(
+                        "COPY {} TO '{}' (FORMAT PARQUET, PARTITION_BY ({}))",
+                        quote_ident(&table),
+                        dir.replace('\'', "''"),
+                        quote_ident(column)
+                    );
+                    run_stor_execute(&conn, &sql, span)?;
+                    dir
+                }
+                None => {
+                    let file = format!("{}/{}.parquet", path.item, table);
+                    let sql = format!(
+                        "COPY {} TO '{}' (FORMAT PARQUET)",
+                        quote_ident(&table),
+                        file.replace('\'', "''")
+                    );
+                    run_stor_execute(&conn, &sql, span)?;
+                    file
+                }
+            };
+            written.push(Value::string(target, span));
+            progress.inc();
+        }
+        progress.finish();
+
+        Ok(Value::list(written, span).into_pipeline_data())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorToDataset {});
+    }
+}
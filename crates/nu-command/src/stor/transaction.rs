@@ -0,0 +1,107 @@
+use super::db::{run_stor_execute, stor_connection};
+use nu_engine::{eval_block_with_early_return, CallExt};
+use nu_protocol::{
+    ast::Call,
+    engine::{Closure, Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct StorTransaction;
+
+impl Command for StorTransaction {
+    fn name(&self) -> &str {
+        "stor transaction"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Any)])
+            .allow_variants_without_examples(true)
+            .required(
+                "closure",
+                SyntaxShape::Closure(None),
+                "the commands to run inside the transaction",
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Run a closure inside a database transaction."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Every `stor` command already shares one connection, so the commands in
+the closure automatically participate in the transaction. It commits when the
+closure finishes and rolls back if the closure errors or is interrupted."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Move rows between tables atomically",
+            example: r#"stor transaction {
+    stor exec "insert into archive select * from logs"
+    stor delete logs --all
+}"#,
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "transaction", "commit", "rollback", "atomic"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let closure: Closure = call.req(engine_state, stack, 0)?;
+        let ctrlc = engine_state.ctrlc.clone();
+
+        // the connection guard must be released before the closure runs, or
+        // the stor commands inside it would deadlock on the shared mutex
+        {
+            let conn = stor_connection(span)?;
+            run_stor_execute(&conn, "BEGIN TRANSACTION", span)?;
+        }
+
+        let block = engine_state.get_block(closure.block_id);
+        let mut callee_stack = stack.captures_to_stack(closure.captures);
+        let result = eval_block_with_early_return(
+            engine_state,
+            &mut callee_stack,
+            block,
+            input,
+            true,
+            true,
+        );
+
+        let conn = stor_connection(span)?;
+        match &result {
+            Ok(_) if !nu_utils::ctrl_c::was_pressed(&ctrlc) => {
+                run_stor_execute(&conn, "COMMIT", span)?;
+            }
+            _ => {
+                run_stor_execute(&conn, "ROLLBACK", span)?;
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorTransaction {});
+    }
+}
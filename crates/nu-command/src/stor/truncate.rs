@@ -0,0 +1,134 @@
+use super::db::{quote_ident, run_stor_execute, stor_connection};
+use super::drop::confirmed;
+use super::progress::StorProgress;
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    record, Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct StorTruncate;
+
+impl Command for StorTruncate {
+    fn name(&self) -> &str {
+        "stor truncate"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .rest("tables", SyntaxShape::String, "names of the tables to empty")
+            .switch(
+                "force",
+                "empty non-empty tables without asking",
+                Some('f'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Quickly delete all rows from one or more tables."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "A table that still contains rows asks for confirmation before its rows
+are deleted; --force skips the prompt for scripts. Returns one row per
+table with the number of rows removed."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Empty the staging table, discarding its rows",
+            example: "stor truncate staging --force",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "truncate", "delete", "empty"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let tables: Vec<String> = call.rest(engine_state, stack, 0)?;
+        let force = call.has_flag("force");
+
+        if tables.is_empty() {
+            return Err(ShellError::MissingParameter {
+                param_name: "tables".into(),
+                span,
+            });
+        }
+
+        let conn = stor_connection(span)?;
+
+        for table in &tables {
+            let count: i64 = conn
+                .query_row(
+                    &format!("SELECT count(*) FROM {}", quote_ident(table)),
+                    [],
+                    |row| row.get(0),
+                )
+                .map_err(|e| {
+                    ShellError::GenericError(
+                        format!("Cannot truncate table {table}"),
+                        e.to_string(),
+                        Some(span),
+                        None,
+                        Vec::new(),
+                    )
+                })?;
+
+            if count > 0 && !force && !confirmed(&format!("Delete {count} rows from {table}? (Y/N)"))
+            {
+                return Err(ShellError::GenericError(
+                    format!("Not truncating table {table}"),
+                    "not confirmed".into(),
+                    Some(span),
+                    Some("pass --force to truncate without confirmation".into()),
+                    Vec::new(),
+                ));
+            }
+        }
+
+        let progress = StorProgress::new(Some(tables.len() as u64), "truncating tables");
+        let mut removed = Vec::new();
+        for table in &tables {
+            let rows =
+                run_stor_execute(&conn, &format!("DELETE FROM {}", quote_ident(table)), span)?;
+            removed.push(Value::record(
+                record! {
+                    "table" => Value::string(table.clone(), span),
+                    "rows_removed" => Value::int(rows as i64, span),
+                },
+                span,
+            ));
+            progress.inc();
+        }
+        progress.finish();
+
+        Ok(Value::list(removed, span).into_pipeline_data())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorTruncate {});
+    }
+}
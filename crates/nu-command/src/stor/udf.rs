@@ -0,0 +1,357 @@
+use super::db::{forget_startup_sql, run_stor_execute, stor_connection};
+use super::functions::{register_scalar_function, StorScalarFunction};
+use duckdb::arrow::array::{Array, StringArray};
+use duckdb::arrow::datatypes::DataType;
+use duckdb::arrow::record_batch::RecordBatch;
+use duckdb::vscalar::{ArrowFunctionSignature, VArrowScalar};
+use nu_engine::{eval_block_with_early_return, CallExt};
+use nu_protocol::{
+    ast::Call,
+    engine::{Closure, Command, EngineState, Stack},
+    record, Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+// Closures registered as UDFs, keyed by the name they were registered under.
+// DuckDB calls back into `dispatch_nu_udf` from its own execution threads, so
+// everything a closure needs to run again is captured here at registration
+// time.
+static NU_UDFS: Lazy<Mutex<HashMap<String, NuUdf>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+struct NuUdf {
+    engine_state: EngineState,
+    stack: Stack,
+    closure: Closure,
+}
+
+#[derive(Clone)]
+pub struct StorUdfRegister;
+
+impl Command for StorUdfRegister {
+    fn name(&self) -> &str {
+        "stor udf register"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("name", SyntaxShape::String, "name the UDF is callable under")
+            .required(
+                "closure",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                "the closure run for each value",
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Register a nu closure as a scalar SQL function."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The closure is invoked through the generic nu_udf() dispatcher, which takes
+the UDF name as its first argument: `SELECT nu_udf('double', x) FROM t`. A
+macro with the registered name is created on top, so `SELECT double(x)` works
+too. Values cross the boundary as strings for now."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Register a doubling function and use it in a query",
+            example: r#"stor udf register double {|x| ($x | into int) * 2 }"#,
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "udf", "function", "closure"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        let closure: Closure = call.req(engine_state, stack, 1)?;
+
+        let udf = NuUdf {
+            engine_state: engine_state.clone(),
+            stack: stack.captures_to_stack(closure.captures.clone()),
+            closure: closure.clone(),
+        };
+
+        if let Ok(mut udfs) = NU_UDFS.lock() {
+            udfs.insert(name.clone(), udf);
+        }
+
+        {
+            let conn = stor_connection(span)?;
+            ensure_dispatcher(&conn, span)?;
+        }
+
+        // a thin macro so the UDF is callable under its own name
+        register_scalar_function(
+            &StorScalarFunction {
+                name: name.clone(),
+                params: vec!["x".into()],
+                body: format!("nu_udf('{}', x)", name.replace('\'', "''")),
+            },
+            span,
+        )?;
+
+        Ok(PipelineData::empty())
+    }
+}
+
+/// Register the generic `nu_udf(name, arg)` dispatcher, once per connection.
+pub(super) fn ensure_dispatcher(
+    conn: &duckdb::Connection,
+    call_span: Span,
+) -> Result<(), ShellError> {
+    conn.register_scalar_function::<NuUdfDispatcher>("nu_udf")
+        .map_err(|e| {
+            ShellError::GenericError(
+                "Failed to register nu_udf dispatcher".into(),
+                e.to_string(),
+                Some(call_span),
+                None,
+                Vec::new(),
+            )
+        })
+}
+
+struct NuUdfDispatcher;
+
+impl VArrowScalar for NuUdfDispatcher {
+    type State = ();
+
+    fn invoke(
+        _: &Self::State,
+        input: RecordBatch,
+    ) -> Result<Arc<dyn Array>, Box<dyn std::error::Error>> {
+        let names = input
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .ok_or("nu_udf: first argument must be the UDF name")?;
+        let args = input
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .ok_or("nu_udf: second argument must be castable to VARCHAR")?;
+
+        let mut results = Vec::with_capacity(args.len());
+        for i in 0..args.len() {
+            if names.is_null(i) || args.is_null(i) {
+                results.push(None);
+                continue;
+            }
+            results.push(Some(run_nu_udf(names.value(i), args.value(i))?));
+        }
+
+        Ok(Arc::new(StringArray::from(results)))
+    }
+
+    fn signatures() -> Vec<ArrowFunctionSignature> {
+        vec![ArrowFunctionSignature::exact(
+            vec![DataType::Utf8, DataType::Utf8],
+            DataType::Utf8,
+        )]
+    }
+}
+
+fn run_nu_udf(name: &str, arg: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let udfs = NU_UDFS.lock().map_err(|e| e.to_string())?;
+    let udf = udfs
+        .get(name)
+        .ok_or_else(|| format!("nu_udf: no UDF registered under the name {name}"))?;
+
+    let span = Span::unknown();
+    let value = Value::string(arg, span);
+
+    let block = udf.engine_state.get_block(udf.closure.block_id);
+    let mut stack = udf.stack.clone();
+    if let Some(var) = block.signature.get_positional(0) {
+        if let Some(var_id) = &var.var_id {
+            stack.add_var(*var_id, value.clone());
+        }
+    }
+
+    let result = eval_block_with_early_return(
+        &udf.engine_state,
+        &mut stack,
+        block,
+        value.into_pipeline_data(),
+        true,
+        true,
+    )
+    .map_err(|e| format!("nu_udf: {e}"))?;
+
+    Ok(result
+        .into_value(span)
+        .as_string()
+        .map_err(|e| format!("nu_udf: {e}"))?)
+}
+
+#[derive(Clone)]
+pub struct StorUdfList;
+
+impl Command for StorUdfList {
+    fn name(&self) -> &str {
+        "stor udf list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "List the nu closures registered as SQL functions."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "See which UDFs this session has registered",
+            example: "stor udf list",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "udf", "list", "functions"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let mut names: Vec<String> = NU_UDFS
+            .lock()
+            .map_err(|e| {
+                ShellError::GenericError(
+                    "Failed to read the UDF registry".into(),
+                    e.to_string(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                )
+            })?
+            .keys()
+            .cloned()
+            .collect();
+        names.sort();
+
+        let rows = names
+            .into_iter()
+            .map(|name| {
+                Value::record(
+                    record! {
+                        "name" => Value::string(&name, span),
+                        "callable_as" => Value::string(format!("{name}(x)"), span),
+                    },
+                    span,
+                )
+            })
+            .collect();
+
+        Ok(Value::list(rows, span).into_pipeline_data())
+    }
+}
+
+#[derive(Clone)]
+pub struct StorUdfRemove;
+
+impl Command for StorUdfRemove {
+    fn name(&self) -> &str {
+        "stor udf remove"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("name", SyntaxShape::String, "name of the UDF to remove")
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Remove a registered UDF and its SQL wrapper."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Remove a UDF registered earlier",
+            example: "stor udf remove double",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "udf", "remove", "unregister"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+
+        let removed = NU_UDFS
+            .lock()
+            .map(|mut udfs| udfs.remove(&name).is_some())
+            .unwrap_or(false);
+        if !removed {
+            return Err(ShellError::GenericError(
+                format!("No UDF registered under {name}"),
+                "nothing to remove".into(),
+                Some(span),
+                Some("see stor udf list for the registered names".into()),
+                Vec::new(),
+            ));
+        }
+
+        let conn = stor_connection(span)?;
+        run_stor_execute(
+            &conn,
+            &format!("DROP MACRO IF EXISTS {}", super::db::quote_ident(&name)),
+            span,
+        )?;
+        forget_startup_sql(&name);
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorUdfRegister {});
+        test_examples(StorUdfList {});
+        test_examples(StorUdfRemove {});
+    }
+}
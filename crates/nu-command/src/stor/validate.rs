@@ -0,0 +1,359 @@
+use super::db::{quote_ident, register_ctrlc, stor_connection};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    record, Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct StorValidate;
+
+impl Command for StorValidate {
+    fn name(&self) -> &str {
+        "stor validate"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::Nothing, Type::Table(vec![])),
+                (Type::Table(vec![]), Type::Table(vec![])),
+            ])
+            .allow_variants_without_examples(true)
+            .required("table", SyntaxShape::String, "table to validate (or validate against)")
+            .named(
+                "not-null",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "columns that must not contain NULLs",
+                Some('n'),
+            )
+            .named(
+                "unique",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "columns whose values must be unique",
+                Some('u'),
+            )
+            .named(
+                "check",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "SQL expressions every row must satisfy",
+                Some('c'),
+            )
+            .switch("strict", "error when any check fails", Some('s'))
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Run data quality checks against a table and report violations."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Without piped input the --not-null/--unique/--check rules run against
+the named table. With piped records the records themselves are checked
+against the table's schema instead — unknown columns, values of the wrong
+type, and NULLs in NOT NULL columns are reported per row — without writing
+anything, so `stor insert` failures can be diagnosed up front."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Check ids for NULLs and duplicates, and amounts for sign",
+                example: r#"stor validate sales --not-null [id] --unique [id] --check ["amount >= 0"]"#,
+                result: None,
+            },
+            Example {
+                description: "Check a file against a table before inserting it",
+                example: "open new.csv | stor validate sales",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "validate", "quality", "check"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        register_ctrlc(&engine_state.ctrlc);
+        let table: String = call.req(engine_state, stack, 0)?;
+        let strict_input = call.has_flag("strict");
+
+        if !matches!(input, PipelineData::Empty) {
+            let conn = stor_connection(span)?;
+            let report = validate_piped(&conn, &table, input, span)?;
+            if strict_input {
+                if let Value::List { vals, .. } = &report {
+                    if !vals.is_empty() {
+                        return Err(ShellError::GenericError(
+                            format!("{} rows would fail insertion into {table}", vals.len()),
+                            "data did not pass validation".into(),
+                            Some(span),
+                            Some("run without --strict to see the full report".into()),
+                            Vec::new(),
+                        ));
+                    }
+                }
+            }
+            return Ok(report.into_pipeline_data());
+        }
+
+        let not_null: Vec<String> = call
+            .get_flag(engine_state, stack, "not-null")?
+            .unwrap_or_default();
+        let unique: Vec<String> = call
+            .get_flag(engine_state, stack, "unique")?
+            .unwrap_or_default();
+        let checks: Vec<String> = call
+            .get_flag(engine_state, stack, "check")?
+            .unwrap_or_default();
+        let strict = call.has_flag("strict");
+
+        let conn = stor_connection(span)?;
+        let table_ident = quote_ident(&table);
+        let mut report = Vec::new();
+        let mut failed = 0;
+
+        for column in &not_null {
+            let violations = count_violations(
+                &conn,
+                &format!(
+                    "SELECT count(*) FROM {table_ident} WHERE {} IS NULL",
+                    quote_ident(column)
+                ),
+                span,
+            )?;
+            report.push(report_row(
+                format!("not-null {column}"),
+                violations,
+                span,
+            ));
+            failed += (violations > 0) as i64;
+        }
+
+        for column in &unique {
+            let violations = count_violations(
+                &conn,
+                &format!(
+                    "SELECT count(*) - count(DISTINCT {0}) FROM {table_ident} WHERE {0} IS NOT NULL",
+                    quote_ident(column)
+                ),
+                span,
+            )?;
+            report.push(report_row(format!("unique {column}"), violations, span));
+            failed += (violations > 0) as i64;
+        }
+
+        for check in &checks {
+            let violations = count_violations(
+                &conn,
+                &format!("SELECT count(*) FROM {table_ident} WHERE NOT ({check})"),
+                span,
+            )?;
+            report.push(report_row(format!("check {check}"), violations, span));
+            failed += (violations > 0) as i64;
+        }
+
+        if strict && failed > 0 {
+            return Err(ShellError::GenericError(
+                format!("{failed} validation checks failed for table {table}"),
+                "data did not pass validation".into(),
+                Some(span),
+                Some("run without --strict to see the full report".into()),
+                Vec::new(),
+            ));
+        }
+
+        Ok(Value::list(report, span).into_pipeline_data())
+    }
+}
+
+fn count_violations(
+    conn: &duckdb::Connection,
+    sql: &str,
+    span: Span,
+) -> Result<i64, ShellError> {
+    conn.query_row(sql, [], |row| row.get(0)).map_err(|e| {
+        ShellError::GenericError(
+            "Failed to run validation query".into(),
+            e.to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    })
+}
+
+fn report_row(check: String, violations: i64, span: Span) -> Value {
+    Value::record(
+        record! {
+            "check" => Value::string(check, span),
+            "passed" => Value::bool(violations == 0, span),
+            "violations" => Value::int(violations, span),
+        },
+        span,
+    )
+}
+
+// Check piped records against the target table's schema without inserting
+// them, reporting one row per problem found.
+fn validate_piped(
+    conn: &duckdb::Connection,
+    table: &str,
+    input: PipelineData,
+    span: Span,
+) -> Result<Value, ShellError> {
+    struct ColumnInfo {
+        name: String,
+        data_type: String,
+        nullable: bool,
+        has_default: bool,
+    }
+
+    let mut schema: Vec<ColumnInfo> = Vec::new();
+    let result = (|| -> Result<(), duckdb::Error> {
+        let mut stmt = conn.prepare(
+            "SELECT column_name, data_type, is_nullable, column_default IS NOT NULL \
+             FROM duckdb_columns() WHERE table_name = ? ORDER BY column_index",
+        )?;
+        let mut rows = stmt.query([table])?;
+        while let Some(row) = rows.next()? {
+            schema.push(ColumnInfo {
+                name: row.get(0)?,
+                data_type: row.get(1)?,
+                nullable: row.get(2)?,
+                has_default: row.get(3)?,
+            });
+        }
+        Ok(())
+    })();
+    result.map_err(|e| {
+        ShellError::GenericError(
+            format!("Failed to read columns of {table}"),
+            e.to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    if schema.is_empty() {
+        return Err(ShellError::GenericError(
+            format!("No table named {table}"),
+            "validation needs an existing table to check against".into(),
+            Some(span),
+            None,
+            Vec::new(),
+        ));
+    }
+
+    let mut report = Vec::new();
+    let mut issue = |row: usize, column: &str, problem: String| {
+        report.push(Value::record(
+            record! {
+                "row" => Value::int(row as i64, span),
+                "column" => Value::string(column, span),
+                "issue" => Value::string(problem, span),
+            },
+            span,
+        ));
+    };
+
+    for (index, value) in input.into_iter().enumerate() {
+        let value_span = value.span();
+        let Value::Record { val: record, .. } = value else {
+            return Err(ShellError::OnlySupportsThisInputType {
+                exp_input_type: "record".into(),
+                wrong_type: value.get_type().to_string(),
+                dst_span: span,
+                src_span: value_span,
+            });
+        };
+
+        for (column, _) in record.iter() {
+            if !schema.iter().any(|info| &info.name == column) {
+                issue(index, column, format!("no column {column} in {table}"));
+            }
+        }
+
+        for info in &schema {
+            match record.iter().find(|(column, _)| **column == info.name) {
+                None => {
+                    if !info.nullable && !info.has_default {
+                        issue(
+                            index,
+                            &info.name,
+                            "missing value for NOT NULL column without default".into(),
+                        );
+                    }
+                }
+                Some((_, value)) => {
+                    if matches!(value, Value::Nothing { .. }) {
+                        if !info.nullable {
+                            issue(index, &info.name, "NULL in NOT NULL column".into());
+                        }
+                    } else if !type_matches(&info.data_type, value) {
+                        issue(
+                            index,
+                            &info.name,
+                            format!("{} value in {} column", value.get_type(), info.data_type),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(Value::list(report, span))
+}
+
+// Whether a nu value would bind cleanly into a column of the given DuckDB
+// type. VARCHAR accepts everything, mirroring the Text fallback in
+// convert_nu_value_to_db_param; numeric columns accept ints but not the
+// other way around.
+fn type_matches(data_type: &str, value: &Value) -> bool {
+    let base = data_type
+        .split('(')
+        .next()
+        .unwrap_or(data_type)
+        .trim()
+        .to_uppercase();
+    match base.as_str() {
+        "TINYINT" | "SMALLINT" | "INTEGER" | "BIGINT" | "HUGEINT" | "UTINYINT" | "USMALLINT"
+        | "UINTEGER" | "UBIGINT" => {
+            matches!(value, Value::Int { .. } | Value::Filesize { .. })
+        }
+        "FLOAT" | "DOUBLE" | "DECIMAL" => matches!(
+            value,
+            Value::Float { .. } | Value::Int { .. } | Value::Filesize { .. }
+        ),
+        "BOOLEAN" => matches!(value, Value::Bool { .. }),
+        "BLOB" => matches!(value, Value::Binary { .. }),
+        "DATE" | "TIMESTAMP" | "TIMESTAMP WITH TIME ZONE" => {
+            matches!(value, Value::Date { .. })
+        }
+        "INTERVAL" => matches!(value, Value::Duration { .. }),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorValidate {});
+    }
+}
@@ -0,0 +1,159 @@
+use super::db::{
+    ensure_table_for_record, insert_record, quote_ident, run_stor_execute, stor_connection,
+};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct StorViewCreate;
+
+impl Command for StorViewCreate {
+    fn name(&self) -> &str {
+        "stor view create"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::Nothing, Type::Nothing),
+                (Type::Table(vec![]), Type::Nothing),
+            ])
+            .allow_variants_without_examples(true)
+            .required("name", SyntaxShape::String, "name of the view to create")
+            .named(
+                "as",
+                SyntaxShape::String,
+                "SELECT statement defining the view",
+                Some('a'),
+            )
+            .switch(
+                "from-pipeline",
+                "materialize the pipeline input into a backing table and define the view over it",
+                Some('p'),
+            )
+            .category(Category::Custom("database".into()))
+    }
+
+    fn usage(&self) -> &str {
+        "Create a view in the in-memory database, from SQL or from pipeline data."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Create a view from a SELECT statement",
+                example: r#"stor view create daily_totals --as "SELECT day, sum(amount) AS total FROM sales GROUP BY day""#,
+                result: None,
+            },
+            Example {
+                description: "Materialize pipeline data and create a view over it",
+                example: "ls | stor view create files --from-pipeline",
+                result: None,
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "duckdb", "view", "select"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        let select: Option<String> = call.get_flag(engine_state, stack, "as")?;
+        let from_pipeline = call.has_flag("from-pipeline");
+
+        let conn = stor_connection(span)?;
+
+        match (select, from_pipeline) {
+            (Some(select), false) => {
+                let sql = format!("CREATE VIEW {} AS {}", quote_ident(&name), select);
+                run_stor_execute(&conn, &sql, span)?;
+            }
+            (None, true) => {
+                let backing_table = format!("{name}__data");
+                materialize_pipeline(&conn, &backing_table, input, span)?;
+                let sql = format!(
+                    "CREATE VIEW {} AS SELECT * FROM {}",
+                    quote_ident(&name),
+                    quote_ident(&backing_table)
+                );
+                run_stor_execute(&conn, &sql, span)?;
+            }
+            _ => {
+                return Err(ShellError::GenericError(
+                    "A view needs exactly one definition".into(),
+                    "use either --as with a SELECT statement or --from-pipeline".into(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                ));
+            }
+        }
+
+        Ok(PipelineData::empty())
+    }
+}
+
+fn materialize_pipeline(
+    conn: &duckdb::Connection,
+    table_name: &str,
+    input: PipelineData,
+    span: nu_protocol::Span,
+) -> Result<(), ShellError> {
+    let mut created = false;
+    let mut columns: Vec<String> = Vec::new();
+
+    for value in input {
+        let val_span = value.span();
+        let Value::Record { val: record, .. } = value else {
+            return Err(ShellError::OnlySupportsThisInputType {
+                exp_input_type: "table".into(),
+                wrong_type: value.get_type().to_string(),
+                dst_span: span,
+                src_span: val_span,
+            });
+        };
+
+        if !created {
+            columns = ensure_table_for_record(conn, table_name, &record, span)?;
+            created = true;
+        }
+
+        insert_record(conn, table_name, &columns, &record, span)?;
+    }
+
+    if !created {
+        return Err(ShellError::GenericError(
+            "No pipeline data to materialize".into(),
+            "--from-pipeline needs at least one input row".into(),
+            Some(span),
+            None,
+            Vec::new(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StorViewCreate {});
+    }
+}